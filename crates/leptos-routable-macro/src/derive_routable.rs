@@ -1,8 +1,9 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{parse_macro_input, spanned::Spanned, Data::{Enum, Struct, Union}, DeriveInput, Ident, Type, Variant, Fields};
 use darling::{FromDeriveInput, FromVariant};
+use crate::localized::RegisteredPath;
 
 /* -------------------------------------------------------------------------------------------------
  * Helper Functions
@@ -28,7 +29,52 @@ fn to_snake_case(s: &str) -> String {
 }
 
 trait IntoChildTokens {
-    fn into_child_tokens(self, view: TokenStream2) -> Option<TokenStream2>;
+    /// One route per registered path, in the same order.
+    fn into_child_tokens(self, view: TokenStream2, paths: &[RegisteredPath]) -> Vec<TokenStream2>;
+}
+
+/// Groups the routes registered for one variant into one child.
+fn with_aliases(routes: &[TokenStream2]) -> Option<TokenStream2> {
+    match routes {
+        [] => None,
+        [route] => Some(route.clone()),
+        routes => Some(quote! { (#(#routes),*) }),
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Options shared by every route attribute
+ * -----------------------------------------------------------------------------------------------*/
+#[derive(std::fmt::Debug, darling::FromMeta)]
+struct RouteOptions {
+    #[darling(flatten)]
+    metadata: crate::route_metadata::RouteMetaArgs,
+
+    /// Extra paths matched by `FromStr` and the router; `Display` keeps emitting `path`.
+    #[darling(default)]
+    aliases: Vec<syn::LitStr>,
+
+    /// `SsrMode` of the route, defaults to the enum-level `ssr`.
+    #[darling(default)]
+    ssr: Option<syn::Expr>,
+
+    /// Guard checked before rendering, defaults to the enum-level `guard`.
+    #[darling(default)]
+    guard: Option<crate::guard_spec::GuardSpec>,
+
+    /// Splits the view into its own wasm chunk, loaded on first visit, when leptos' `csr` or
+    /// `hydrate` feature is on. On a `parent_route` this covers the parent's own view only;
+    /// variants of the nested enum need their own `lazy`.
+    #[darling(default)]
+    lazy: bool,
+
+    /// View shown while a `lazy` route's chunk loads, defaults to the enum-level `loading`.
+    #[darling(default)]
+    loading: Option<syn::Expr>,
+
+    /// Loads the route's data from the typed variant, read with `use_route_data`.
+    #[darling(default)]
+    loader: Option<syn::Expr>,
 }
 
 /* -------------------------------------------------------------------------------------------------
@@ -40,20 +86,52 @@ struct RouteVariant {
     #[allow(unused)] ident: Ident,
     #[allow(unused)] fields: darling::ast::Fields<syn::Type>,
 
+    #[darling(flatten)]
+    options: RouteOptions,
+
     // Arguments
     path: syn::LitStr,
+    static_params: Option<syn::Expr>,
 }
 
 impl IntoChildTokens for RouteVariant {
-    fn into_child_tokens(self, view: TokenStream2) -> Option<TokenStream2> {
+    fn into_child_tokens(self, view: TokenStream2, paths: &[RegisteredPath]) -> Vec<TokenStream2> {
         let path = self.path;
-        Some(quote! {
-            ::leptos_router::components::Route(
-                ::leptos_router::components::RouteProps::builder()
-                    .path(::leptos_router::path!(#path))
-                    .view(#view)
-                    .build())
-        })
+        // Prerender the instances listed by `static_params`
+        let ssr = match (&self.static_params, &self.options.ssr) {
+            (Some(static_params), _) => Some(quote! {
+                .ssr(::leptos_router::SsrMode::Static(
+                    ::leptos_router::static_routes::StaticRoute::new().prerender_params(|| async move {
+                        ::leptos_routable::prelude::static_params_map(
+                            #path,
+                            #static_params().into_iter().map(|route| route.to_string()),
+                        )
+                    })
+                ))
+            }),
+            (None, Some(ssr)) => Some(quote! { .ssr(#ssr) }),
+            (None, None) => None,
+        };
+        let route = |path: &syn::LitStr, ssr: Option<&TokenStream2>| {
+            let router_path = crate::localized::router_path(path);
+            quote! {
+                ::leptos_router::components::Route(
+                    ::leptos_router::components::RouteProps::builder()
+                        .path(#router_path)
+                        .view(#view)
+                        #ssr
+                        .build())
+            }
+        };
+        // Aliases are served but never prerendered, `static_params` only lists canonical paths
+        let alias_ssr = self.options.ssr.as_ref().map(|ssr| quote! { .ssr(#ssr) });
+        paths
+            .iter()
+            .map(|registered| match registered.path == path {
+                true => route(&registered.path, ssr.as_ref()),
+                false => route(&registered.path, alias_ssr.as_ref()),
+            })
+            .collect()
     }
 }
 
@@ -64,21 +142,31 @@ impl IntoChildTokens for RouteVariant {
 #[darling(attributes(parent_route))]
 struct ParentRouteVariant {
     #[allow(unused)] ident: Ident,
-    fields: darling::ast::Fields<syn::Type>,
+    fields: darling::ast::Fields<syn::Field>,
     #[allow(unused)] routable: Option<Ident>,
 
+    #[darling(flatten)]
+    options: RouteOptions,
+
     // Arguments
     path: syn::LitStr,
-    ssr: Option<syn::Expr>,
 }
 
 impl IntoChildTokens for ParentRouteVariant {
-    fn into_child_tokens(self, view: TokenStream2) -> Option<TokenStream2> {
-        let path = self.path;
-        let ssr = self.ssr.unwrap_or(syn::parse_quote!(Default::default()));
-        // There can only be one, error elsewhere ensures.
-        let inner_ident = self.fields.fields.into_iter().next()?;
-        Some(quote! { #inner_ident::parent_route(::leptos_router::path!(#path), #view, #ssr) })
+    fn into_child_tokens(self, view: TokenStream2, paths: &[RegisteredPath]) -> Vec<TokenStream2> {
+        let ssr = self.options.ssr.unwrap_or(syn::parse_quote!(Default::default()));
+        // The unnamed or `#[nested]` field, `parse_variant` ensures there is one.
+        let Some(inner) = crate::field_attrs::nested_field(&self.fields.fields) else {
+            return Vec::new();
+        };
+        let inner_ident = &inner.ty;
+        paths
+            .iter()
+            .map(|RegisteredPath { path, locale, .. }| {
+                let router_path = crate::localized::router_path(path);
+                quote! { #inner_ident::parent_route(#router_path, #view, #ssr, #locale) }
+            })
+            .collect()
     }
 }
 
@@ -91,6 +179,9 @@ struct ProtectedRouteVariant {
     #[allow(unused)] ident: Ident,
     #[allow(unused)] fields: darling::ast::Fields<syn::Type>,
 
+    #[darling(flatten)]
+    options: RouteOptions,
+
     // Arguments
     path: syn::LitStr,
     condition: syn::Expr,
@@ -99,22 +190,40 @@ struct ProtectedRouteVariant {
 }
 
 impl IntoChildTokens for ProtectedRouteVariant {
-    fn into_child_tokens(self, view: TokenStream2) -> Option<TokenStream2> {
-        let path = self.path;
+    fn into_child_tokens(self, view: TokenStream2, paths: &[RegisteredPath]) -> Vec<TokenStream2> {
         let condition = self.condition;
-        let redirect_path = self.redirect_path;
+        let redirect_path = redirect_path_tokens(&self.redirect_path);
         let fallback = self.fallback;
-        Some(quote! {
-             ::leptos_router::components::ProtectedRoute(
-                 ::leptos_router::components::ProtectedRouteProps::builder()
-                     .path(::leptos_router::path!(#path))
-                     .view(#view)
-                     .condition(#condition)
-                     .redirect_path(#redirect_path)
-                     .fallback(#fallback)
-                     .build()
-             )
-        })
+        let ssr = self.options.ssr.map(|ssr| quote! { .ssr(#ssr) });
+        let route = |path: &syn::LitStr| {
+            let router_path = crate::localized::router_path(path);
+            quote! {
+                ::leptos_router::components::ProtectedRoute(
+                    ::leptos_router::components::ProtectedRouteProps::builder()
+                        .path(#router_path)
+                        .view(#view)
+                        .condition(#condition)
+                        .redirect_path(#redirect_path)
+                        .fallback(#fallback)
+                        #ssr
+                        .build()
+                )
+            }
+        };
+        paths.iter().map(|registered| route(&registered.path)).collect()
+    }
+}
+
+/// The `redirect_path` of protected routes, giving the current location to route targets with
+/// a `#[query(return_to)]` field. Targets that aren't routes only need to be `Display`.
+fn redirect_path_tokens(redirect_path: &syn::Expr) -> TokenStream2 {
+    quote! {
+        move || {
+            use ::leptos_routable::prelude::IntoRedirectTarget as _;
+            ::leptos_routable::prelude::redirect_with_return_to(
+                ::leptos_routable::prelude::RedirectPath((#redirect_path)()).into_redirect_target(),
+            )
+        }
     }
 }
 
@@ -125,26 +234,40 @@ impl IntoChildTokens for ProtectedRouteVariant {
 #[darling(attributes(protected_parent_route))]
 struct ProtectedParentRouteVariant {
     #[allow(unused)] ident: Ident,
-    fields: darling::ast::Fields<syn::Type>,
+    fields: darling::ast::Fields<syn::Field>,
+
+    #[darling(flatten)]
+    options: RouteOptions,
 
     // Arguments
     path: syn::LitStr,
     condition: syn::Expr,
     redirect_path: syn::Expr,
     fallback: syn::Expr,
-    ssr: Option<syn::Expr>,
 }
 
 impl IntoChildTokens for ProtectedParentRouteVariant {
-    fn into_child_tokens(self, view: TokenStream2) -> Option<TokenStream2> {
-        let path = self.path;
+    fn into_child_tokens(self, view: TokenStream2, paths: &[RegisteredPath]) -> Vec<TokenStream2> {
         let condition = self.condition;
-        let redirect_path = self.redirect_path;
+        let redirect_path = redirect_path_tokens(&self.redirect_path);
         let fallback = self.fallback;
-        let ssr = self.ssr.unwrap_or(syn::parse_quote!(Default::default()));
-        // There can only be one, error elsewhere ensures.
-        let inner_ident = self.fields.fields.into_iter().next()?;
-        Some(quote! { #inner_ident::protected_parent_route(::leptos_router::path!(#path), #view, #condition, #fallback.into(), #redirect_path, #ssr) })
+        let ssr = self.options.ssr.unwrap_or(syn::parse_quote!(Default::default()));
+        // The unnamed or `#[nested]` field, `parse_variant` ensures there is one.
+        let Some(inner) = crate::field_attrs::nested_field(&self.fields.fields) else {
+            return Vec::new();
+        };
+        let inner_ident = &inner.ty;
+        paths
+            .iter()
+            .map(|RegisteredPath { path, locale, .. }| {
+                let router_path = crate::localized::router_path(path);
+                quote! {
+                    #inner_ident::protected_parent_route(
+                        #router_path, #view, #condition, #fallback.into(), #redirect_path, #ssr, #locale,
+                    )
+                }
+            })
+            .collect()
     }
 }
 
@@ -184,16 +307,37 @@ pub(crate) struct RoutableConfiguration {
 
     #[darling(default)]
     pub(crate) module_organization: Option<String>,
+
+    /// Renders the current route's `<Title>`/`<Meta>` next to the routes. Needs the `meta`
+    /// feature, and only works on the root enum.
+    #[darling(default)]
+    pub(crate) inject_title: bool,
+
+    /// Default `SsrMode` for every route that doesn't set its own `ssr`.
+    #[darling(default)]
+    pub(crate) ssr: Option<syn::Expr>,
+
+    /// Default guard for every route that doesn't set its own `guard` (`guard = none` opts out).
+    #[darling(default)]
+    pub(crate) guard: Option<crate::guard_spec::GuardSpec>,
+
+    /// Locales whose prefix (`/de/...`) selects the `path_<locale>` paths of every variant.
+    #[darling(default)]
+    pub(crate) locales: Vec<syn::LitStr>,
+
+    /// Default view shown while a `lazy` route's chunk loads, for routes without their own `loading`.
+    #[darling(default)]
+    pub(crate) loading: Option<syn::Expr>,
 }
 
 impl IntoChildTokens for RouteKind {
-    fn into_child_tokens(self, view: TokenStream2) -> Option<TokenStream2> {
+    fn into_child_tokens(self, view: TokenStream2, paths: &[RegisteredPath]) -> Vec<TokenStream2> {
         match self {
-            Self::Route(route) => route.into_child_tokens(view),
-            Self::ParentRoute(parent) => parent.into_child_tokens(view),
-            Self::ProtectedRoute(protected) => protected.into_child_tokens(view),
-            Self::ProtectedParentRoute(protected_parent) => protected_parent.into_child_tokens(view),
-            Self::None => None
+            Self::Route(route) => route.into_child_tokens(view, paths),
+            Self::ParentRoute(parent) => parent.into_child_tokens(view, paths),
+            Self::ProtectedRoute(protected) => protected.into_child_tokens(view, paths),
+            Self::ProtectedParentRoute(protected_parent) => protected_parent.into_child_tokens(view, paths),
+            Self::None => Vec::new(),
         }
     }
 }
@@ -265,6 +409,88 @@ enum RouteKind {
     None,
 }
 
+impl RouteKind {
+    fn options(&self) -> Option<&RouteOptions> {
+        match self {
+            Self::Route(route) => Some(&route.options),
+            Self::ParentRoute(parent) => Some(&parent.options),
+            Self::ProtectedRoute(protected) => Some(&protected.options),
+            Self::ProtectedParentRoute(protected_parent) => Some(&protected_parent.options),
+            Self::None => None,
+        }
+    }
+
+    fn options_mut(&mut self) -> Option<&mut RouteOptions> {
+        match self {
+            Self::Route(route) => Some(&mut route.options),
+            Self::ParentRoute(parent) => Some(&mut parent.options),
+            Self::ProtectedRoute(protected) => Some(&mut protected.options),
+            Self::ProtectedParentRoute(protected_parent) => Some(&mut protected_parent.options),
+            Self::None => None,
+        }
+    }
+
+    fn metadata(&self) -> Option<&crate::route_metadata::RouteMetaArgs> {
+        self.options().map(|options| &options.metadata)
+    }
+
+    /// Fills in the enum-level `ssr` default. Routes with `static_params` keep `SsrMode::Static`.
+    fn apply_default_ssr(&mut self, default: Option<&syn::Expr>) {
+        if self.static_params().is_some() {
+            return;
+        }
+        if let Some(options) = self.options_mut().filter(|options| options.ssr.is_none()) {
+            options.ssr = default.cloned();
+        }
+    }
+
+    fn path(&self) -> Option<&syn::LitStr> {
+        match self {
+            Self::Route(route) => Some(&route.path),
+            Self::ParentRoute(parent) => Some(&parent.path),
+            Self::ProtectedRoute(protected) => Some(&protected.path),
+            Self::ProtectedParentRoute(protected_parent) => Some(&protected_parent.path),
+            Self::None => None,
+        }
+    }
+
+    fn guard(&self) -> Option<&crate::guard_spec::GuardSpec> {
+        self.options()?.guard.as_ref()
+    }
+
+    /// `Some` with the route's own `loading` view for `lazy` routes.
+    fn lazy(&self) -> Option<Option<&syn::Expr>> {
+        let options = self.options()?;
+        options.lazy.then_some(options.loading.as_ref())
+    }
+
+    fn loader(&self) -> Option<&syn::Expr> {
+        self.options()?.loader.as_ref()
+    }
+
+    fn loading(&self) -> Option<&syn::Expr> {
+        self.options()?.loading.as_ref()
+    }
+
+    fn static_params(&self) -> Option<&syn::Expr> {
+        match self {
+            Self::Route(route) => route.static_params.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn info_kind(&self) -> Option<TokenStream2> {
+        let kind = match self {
+            Self::Route(_) => quote! { Route },
+            Self::ParentRoute(_) => quote! { Parent },
+            Self::ProtectedRoute(_) => quote! { Protected },
+            Self::ProtectedParentRoute(_) => quote! { ProtectedParent },
+            Self::None => return None,
+        };
+        Some(quote! { ::leptos_routable::prelude::RouteKind::#kind })
+    }
+}
+
 /* -------------------------------------------------------------------------------------------------
  * `#[derive(Routable)]` implementation
  * -----------------------------------------------------------------------------------------------*/
@@ -299,8 +525,26 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
         }
     };
 
-    let mut children = Vec::new();
+    // Legacy paths come first so they never fall through to a wildcard route
+    let locales: Vec<String> = config.locales.iter().map(syn::LitStr::value).collect();
+
+    let mut children = match crate::redirect::RedirectArgs::from_attrs(&input_ast.attrs) {
+        Ok(redirects) => redirects.iter().map(|redirect| redirect.to_child_tokens(&config.ident)).collect::<Vec<_>>(),
+        Err(err) => return err.write_errors().into(),
+    };
+    // Nested enums register their routes for the locale of the parent route they are under
+    let mut nested_children: Vec<TokenStream2> = children
+        .iter()
+        .map(|redirect| quote! {
+            __children.push(::leptos_router::any_nested_route::IntoAnyNestedRoute::into_any_nested_route(#redirect));
+        })
+        .collect();
     let mut fallback = None::<TokenStream2>;
+    let mut metadata_arms = crate::route_metadata::MetadataArms::new();
+    let mut route_table = crate::route_table::RouteTable::new();
+    let mut lazy_items = Vec::new();
+    let mut nested_head_checks = Vec::new();
+    let mut prefetch_arms = Vec::new();
 
     // Determine if we need state support (only with module_organization)
     let state_store_type = config.state_suffix.as_ref().and_then(|_suffix| {
@@ -315,11 +559,43 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
             Ok(kind) => kind,
             Err(err) => return err.write_errors().into(),
         };
+        if let Err(err) = metadata_arms.push(&config.ident, variant, route_kind.as_ref().and_then(RouteKind::metadata)) {
+            return err.to_compile_error().into();
+        }
+        // Guarded routes render through the guard, the enum-level one unless overridden
+        let guard = route_kind
+            .as_ref()
+            .and_then(|kind| kind.guard().or(config.guard.as_ref()))
+            .and_then(crate::guard_spec::GuardSpec::to_type_tokens);
+        let is_protected = matches!(route_kind, Some(RouteKind::ProtectedRoute(_) | RouteKind::ProtectedParentRoute(_)));
+        if let Err(err) = route_table.push(
+            &config.ident,
+            variant,
+            route_kind.as_ref().and_then(RouteKind::info_kind),
+            guard.is_some() || is_protected,
+            route_kind.as_ref().and_then(RouteKind::metadata),
+            route_kind.as_ref().and_then(RouteKind::static_params),
+        ) {
+            return err.to_compile_error().into();
+        }
+
+        if let Some(nested) = crate::field_attrs::nested_field(&variant.fields) {
+            let nested_ty = &nested.ty;
+            let message = format!(
+                "`{}` is nested under `{}::{}`, so it can't use `#[routes(inject_title)]`; declare it on the root enum instead",
+                quote!(#nested_ty).to_string().replace(' ', ""),
+                config.ident,
+                variant.ident,
+            );
+            nested_head_checks.push(quote_spanned! {nested_ty.span()=>
+                const _: () = ::core::assert!(!<#nested_ty as ::leptos_routable::prelude::Routable>::INJECT_TITLE, #message);
+            });
+        }
 
         // Determine view path based on module_organization
         let view_path = if let Some(ref module_prefix) = config.module_organization {
             // Check if this is a parent route (has unnamed fields)
-            let is_parent = matches!(&variant.fields, syn::Fields::Unnamed(_));
+            let is_parent = crate::field_attrs::nested_field(&variant.fields).is_some();
             crate::utils::build_module_view_path(&variant.ident, is_parent, module_prefix)
         } else {
             // Traditional view naming with prefix/suffix
@@ -332,12 +608,90 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
             Err(err) => return err.to_compile_error().into(),
         }
 
-        // No longer generate per-route wrappers
-        let view_to_use = view_path;
+        prefetch_arms.extend(crate::prefetch::prefetch_arm(
+            &config.ident,
+            variant,
+            route_kind.as_ref().and_then(RouteKind::loader),
+            route_kind.as_ref().and_then(RouteKind::lazy).is_some(),
+        ));
 
-        if let Some(kind) = route_kind {
-            if let Some(child_ts) = kind.into_child_tokens(view_to_use) {
-                children.push(child_ts);
+        // Lazy views load from their own chunk; the guard below still runs before it is fetched
+        let view_path = match route_kind.as_ref().and_then(RouteKind::lazy) {
+            Some(loading) => {
+                let (item, view) = crate::lazy::split_view(
+                    &config.ident,
+                    &variant.ident,
+                    &view_path,
+                    loading.or(config.loading.as_ref()),
+                );
+                lazy_items.push(item);
+                view
+            }
+            None => view_path,
+        };
+
+        // Parent views mark where their nested enum's part of the URL starts
+        let is_parent = matches!(route_kind, Some(RouteKind::ParentRoute(_) | RouteKind::ProtectedParentRoute(_)));
+        let view_path = if is_parent {
+            quote! { move || { ::leptos_routable::prelude::provide_route_base(); (#view_path)() } }
+        } else {
+            view_path
+        };
+
+        // Loaders start as the route renders, alongside the lazy chunk; guarded routes only load once allowed
+        let view_path = match route_kind.as_ref().and_then(RouteKind::loader) {
+            Some(loader) => {
+                let enum_ident = &config.ident;
+                let variant_ident = &variant.ident;
+                quote! {
+                    move || ::leptos_routable::prelude::with_route_data(
+                        |__route: &#enum_ident| matches!(__route, #enum_ident::#variant_ident { .. }),
+                        #is_parent,
+                        #loader,
+                        #view_path,
+                    )
+                }
+            }
+            None => view_path,
+        };
+
+        let view_to_use = match guard {
+            Some(guard) => {
+                let enum_ident = &config.ident;
+                let variant_ident = &variant.ident;
+                quote! {
+                    move || ::leptos_routable::prelude::guarded::<#guard, _, _, _>(
+                        |__route: &#enum_ident| matches!(__route, #enum_ident::#variant_ident { .. }),
+                        #is_parent,
+                        #view_path,
+                    )
+                }
+            }
+            None => view_path,
+        };
+
+        if let Some(mut kind) = route_kind {
+            kind.apply_default_ssr(config.ssr.as_ref());
+            let paths = match (kind.path(), kind.options()) {
+                (Some(path), Some(options)) => {
+                    let localized = &options.metadata.localized.paths;
+                    if let Err(err) = crate::localized::validate(&locales, &path.value(), localized) {
+                        return err.to_compile_error().into();
+                    }
+                    crate::localized::registered_paths(&locales, path, &options.aliases, localized)
+                }
+                _ => Vec::new(),
+            };
+            let routes = kind.into_child_tokens(view_to_use, &paths);
+            children.extend(with_aliases(&routes));
+            for (route, RegisteredPath { check, .. }) in routes.iter().zip(&paths) {
+                let push = quote! {
+                    __children.push(::leptos_router::any_nested_route::IntoAnyNestedRoute::into_any_nested_route(#route));
+                };
+                nested_children.push(match check {
+                    Some(check) => quote! { if #check { #push } },
+                    None => push,
+                });
             }
         }
     }
@@ -409,7 +763,7 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
                 });
 
                 // If this is a parent route, check that SubState exists
-                if matches!(&variant.fields, syn::Fields::Unnamed(_)) {
+                if crate::field_attrs::nested_field(&variant.fields).is_some() {
                     // Build path to SubState
                     let sub_state_path = crate::utils::build_module_state_path(
                         &variant.ident,
@@ -476,7 +830,7 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
             });
 
             // If it's a parent route, also generate helpers for SubState
-            if matches!(&variant.fields, syn::Fields::Unnamed(_)) {
+            if crate::field_attrs::nested_field(&variant.fields).is_some() {
                 let sub_state_type = crate::utils::build_module_state_path(
                     &variant.ident,
                     true,
@@ -527,11 +881,11 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
     // Generate state provider methods
     // 1. Generate __provide_contexts for nested enums (those WITHOUT state_suffix) - only for module-based state
     // 2. Generate provide_state_contexts for root enum (one WITH state_suffix)
-    let nested_provide_method = if state_store_type.is_none() && config.module_organization.is_some() {
-        let module_prefix = config.module_organization.as_ref().unwrap();
-        generate_nested_provide_method_with_modules(&enum_ident, data, module_prefix)
-    } else {
-        quote! {}
+    let nested_provide_method = match config.module_organization.as_ref() {
+        Some(module_prefix) if state_store_type.is_none() => {
+            generate_nested_provide_method_with_modules(&enum_ident, data, module_prefix)
+        }
+        _ => quote! {},
     };
 
     let root_provide_method = if let Some(ref state_store_type) = state_store_type {
@@ -541,10 +895,93 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    let nested_ident = Ident::new("nested", Span2::call_site());
+    let variant_chain_arms = data.variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let variant_name = variant_ident.to_string();
+        if let Some(nested) = crate::field_attrs::nested_field(&variant.fields) {
+            let pat = crate::field_attrs::nested_pattern(&enum_ident, variant_ident, nested, &nested_ident);
+            return quote! {
+                #pat => {
+                    let mut chain = vec![#variant_name];
+                    chain.extend(Routable::variant_chain(nested));
+                    chain
+                }
+            };
+        }
+        match &variant.fields {
+            Fields::Unit => quote! { #enum_ident::#variant_ident => vec![#variant_name], },
+            _ => quote! { #enum_ident::#variant_ident { .. } => vec![#variant_name], },
+        }
+    });
+
+    // Routes with a `#[query(return_to)]` field accept the route a redirect came from
+    let return_to_arms = data.variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        if let Some(nested) = crate::field_attrs::nested_field(&variant.fields) {
+            let pat = crate::field_attrs::nested_pattern(&enum_ident, variant_ident, nested, &nested_ident);
+            return Ok(Some(quote! { #pat => Routable::set_return_to(nested, __from), }));
+        }
+        for field in &variant.fields {
+            if crate::field_attrs::FieldAttrs::from_field(field)?.is_return_to() {
+                let field_ident = &field.ident;
+                return Ok(Some(quote! {
+                    #enum_ident::#variant_ident { #field_ident: return_to, .. } => {
+                        if return_to.is_none() {
+                            *return_to = __from.parse().ok();
+                        }
+                    }
+                }));
+            }
+        }
+        Ok(None)
+    });
+    let return_to_arms = match return_to_arms.collect::<syn::Result<Vec<_>>>() {
+        Ok(arms) => arms.into_iter().flatten().collect::<Vec<_>>(),
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let localized_path_arms = match crate::localized::localized_path_arms(&enum_ident, data) {
+        Ok(arms) => arms,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    // Enums with their own `locales` parse their own prefix wherever they are nested
+    let parse_in_locale = match locales.is_empty() {
+        true => quote! { Self::__parse_in_locale(input, __locale) },
+        false => quote! { <Self as ::std::str::FromStr>::from_str(input) },
+    };
+
+    let crate::route_metadata::MetadataArms {
+        title: title_arms,
+        description: description_arms,
+        meta: meta_arms,
+        breadcrumbs: breadcrumb_arms,
+    } = metadata_arms;
+
+    // Renders `<Title>`/`<Meta>` for the current route next to the routes (`meta` feature).
+    let crate::route_table::RouteTable {
+        entries: route_table_entries,
+        static_routes,
+        prerender_routes,
+    } = route_table;
+
+    let with_route_head = if config.inject_title {
+        quote! { (::leptos_routable::__route_head!(Self), __routes) }
+    } else {
+        quote! { __routes }
+    };
+    let inject_title = config.inject_title;
+
     let routable_impl = quote! {
         // Compile-time validation of state fields
         #field_validation
 
+        // Split points of `lazy` route views
+        #(#lazy_items)*
+
+        // Nested enums can't render their own route head
+        #(#nested_head_checks)*
+
         // Generate context helper methods
         #context_helpers
 
@@ -556,6 +993,105 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
          * `Routable` implementation
          * ---------------------------------------------------------------------------------------*/
         impl Routable for #enum_ident {
+            const ROUTE_TABLE: &'static [::leptos_routable::prelude::RouteInfo] = &[
+                #(#route_table_entries),*
+            ];
+
+            fn route_entries() -> &'static [::leptos_routable::prelude::RouteEntry] {
+                static ENTRIES: ::std::sync::OnceLock<::std::vec::Vec<::leptos_routable::prelude::RouteEntry>> =
+                    ::std::sync::OnceLock::new();
+                ENTRIES.get_or_init(|| ::leptos_routable::prelude::route_entries(Self::ROUTE_TABLE))
+            }
+
+            fn all_static_routes() -> impl Iterator<Item = Self> {
+                let mut __routes = Vec::new();
+                #(#static_routes)*
+                __routes.into_iter()
+            }
+
+            fn prerender_routes() -> Vec<Self> {
+                let mut __routes = Vec::new();
+                #(#prerender_routes)*
+                __routes
+            }
+
+            const LOCALES: &'static [&'static str] = &[#(#locales),*];
+
+            const INJECT_TITLE: bool = #inject_title;
+
+            fn parse_localized(
+                input: &str,
+            ) -> Result<(Self, Option<&'static str>), ::leptos_routable::prelude::RouteParseError> {
+                match ::leptos_routable::prelude::split_locale(input, Self::LOCALES) {
+                    Some((__locale, __rest)) => Self::__parse_in_locale(&__rest, Some(__locale)).map(|route| (route, Some(__locale))),
+                    None => Self::__parse_in_locale(input, None).map(|route| (route, None)),
+                }
+            }
+
+            fn parse_in_locale(
+                input: &str,
+                __locale: Option<&'static str>,
+            ) -> Result<Self, ::leptos_routable::prelude::RouteParseError> {
+                #parse_in_locale
+            }
+
+            #[allow(unused_variables, unreachable_patterns)]
+            fn localized_path(&self, __locale: &str) -> String {
+                match self {
+                    #(#localized_path_arms)*
+                    _ => "/".to_string(),
+                }
+            }
+
+            fn variant_chain(&self) -> Vec<&'static str> {
+                match self {
+                    #(#variant_chain_arms)*
+                }
+            }
+
+            fn prefetch(&self) {
+                ::leptos_routable::prelude::Routable::prefetch_in(self, &::std::string::ToString::to_string(self));
+            }
+
+            #[allow(unused_variables, unreachable_patterns)]
+            fn prefetch_in(&self, __url: &str) {
+                match self {
+                    #(#prefetch_arms)*
+                    _ => {}
+                }
+            }
+
+            #[allow(unused_variables, unreachable_patterns)]
+            fn set_return_to(&mut self, __from: &str) {
+                match self {
+                    #(#return_to_arms)*
+                    _ => {}
+                }
+            }
+
+            fn title(&self) -> Option<String> {
+                match self {
+                    #(#title_arms)*
+                }
+            }
+
+            fn description(&self) -> Option<String> {
+                match self {
+                    #(#description_arms)*
+                }
+            }
+
+            fn meta(&self) -> &'static [(&'static str, &'static str)] {
+                match self {
+                    #(#meta_arms)*
+                }
+            }
+
+            fn breadcrumbs(&self) -> Vec<::leptos_routable::prelude::Breadcrumb<Self>> {
+                match self {
+                    #(#breadcrumb_arms)*
+                }
+            }
 
             /* -------------------------------------------------------------------------------------
              * `Routes` implementation
@@ -563,17 +1099,20 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
             fn routes() -> impl ::leptos::IntoView {
                 #state_init
 
-                ::leptos_router::components::Routes(
+                let __routes = ::leptos_router::components::Routes(
                     ::leptos_router::components::RoutesProps::builder()
                         .transition(#transition)
                         .fallback(#fallback)
                         .children(
                             ::leptos::children::ToChildren::to_children(move || {
+                                #[allow(unused_variables)]
+                                let __locale: Option<&'static str> = None;
                                 (#(#children),*)
                             })
                         )
                         .build()
-                )
+                );
+                #with_route_head
             }
 
             /* -------------------------------------------------------------------------------------
@@ -582,17 +1121,20 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
             fn flat_routes() -> impl ::leptos::IntoView {
                 #state_init
 
-                ::leptos_router::components::FlatRoutes(
+                let __routes = ::leptos_router::components::FlatRoutes(
                     ::leptos_router::components::FlatRoutesProps::builder()
                         .transition(#transition)
                         .fallback(#fallback)
                         .children(
                             ::leptos::children::ToChildren::to_children(move || {
+                                #[allow(unused_variables)]
+                                let __locale: Option<&'static str> = None;
                                 (#(#children),*)
                             })
                         )
                         .build()
-                )
+                );
+                #with_route_head
             }
 
             /* -------------------------------------------------------------------------------------
@@ -612,6 +1154,7 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
                 path: Path,
                 view: View,
                 ssr: ::leptos_router::SsrMode,
+                __locale: Option<&'static str>,
             ) -> impl ::leptos_router::MatchNestedRoutes + Clone
             where
                 Path: Send
@@ -629,7 +1172,9 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
                         .ssr(ssr)
                         .children(
                             ::leptos::children::ToChildren::to_children(move || {
-                                (#(#children),*)
+                                let mut __children: Vec<::leptos_router::any_nested_route::AnyNestedRoute> = Vec::new();
+                                #(#nested_children)*
+                                ::leptos::tachys::view::iterators::StaticVec::from(__children)
                             })
                         )
                         .build()
@@ -653,6 +1198,7 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
                 fallback: ::leptos::children::ViewFn,
                 redirect_path: RedirectPathFn,
                 ssr: ::leptos_router::SsrMode,
+                __locale: Option<&'static str>,
             ) -> impl ::leptos_router::MatchNestedRoutes + Clone
             where
                 Path: Send
@@ -676,7 +1222,9 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
                         .redirect_path(redirect_path)
                         .children(
                             ::leptos::children::ToChildren::to_children(move || {
-                                (#(#children),*)
+                                let mut __children: Vec<::leptos_router::any_nested_route::AnyNestedRoute> = Vec::new();
+                                #(#nested_children)*
+                                ::leptos::tachys::view::iterators::StaticVec::from(__children)
                             })
                         )
                         .ssr(ssr)
@@ -691,7 +1239,7 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
         Err(e) => return e.to_compile_error().into(),
     };
 
-    let from_str_impl = match generate_from_str_impl(&enum_ident, data) {
+    let from_str_impl = match generate_from_str_impl(&enum_ident, data, &locales) {
         Ok(ts) => ts,
         Err(e) => return e.to_compile_error().into(),
     };
@@ -713,6 +1261,7 @@ pub fn derive_routable_impl(input: TokenStream) -> TokenStream {
 fn generate_from_str_impl(
     enum_ident: &syn::Ident,
     data: &syn::DataEnum,
+    locales: &[String],
 ) -> syn::Result<proc_macro2::TokenStream> {
     let mut match_arms = Vec::new();
 
@@ -727,7 +1276,7 @@ fn generate_from_str_impl(
                         let field_ty = &unnamed.unnamed[0].ty;
                         match_arms.push(quote! {
                             // Try nested route parsing
-                            if let Ok(nested) = <#field_ty as ::std::str::FromStr>::from_str(input) {
+                            if let Ok(nested) = <#field_ty as ::leptos_routable::prelude::Routable>::parse_in_locale(input, __locale) {
                                 return Ok(#enum_ident::#variant_ident(nested));
                             }
                         });
@@ -737,30 +1286,63 @@ fn generate_from_str_impl(
             }
         };
 
-        let segments = crate::to_href_display::parse_segments(&route_path);
-        let pattern_match = generate_pattern_match(&segments, &variant.fields, enum_ident, variant_ident)?;
-        match_arms.push(pattern_match);
+        let aliases = crate::to_href_display::find_route_aliases(&variant.attrs);
+        crate::to_href_display::validate_aliases(&route_path, &aliases)?;
+        let localized = crate::localized::find_localized_paths(&variant.attrs);
+        for (route_path, locale_check) in crate::localized::locale_checked_paths(&route_path, &aliases, &localized, locales) {
+            let segments = crate::to_href_display::parse_segments(&route_path);
+            let pattern_match = generate_pattern_match(&segments, &variant.fields, enum_ident, variant_ident)?;
+            match_arms.push(match locale_check {
+                Some(locale_check) => quote! { if #locale_check { #pattern_match } },
+                None => pattern_match,
+            });
+        }
     }
 
     let parse_url_parts = parse_url_parts_tokens();
 
     Ok(quote! {
-        impl ::std::str::FromStr for #enum_ident {
-            type Err = String;
-
-            fn from_str(input: &str) -> Result<Self, Self::Err> {
+        impl #enum_ident {
+            /// Parses `input` against the paths registered for `__locale`, which has already
+            /// been stripped off, or against the unprefixed paths for `None`.
+            #[allow(unused_variables)]
+            fn __parse_in_locale(
+                input: &str,
+                __locale: Option<&'static str>,
+            ) -> Result<Self, ::leptos_routable::prelude::RouteParseError> {
                 #parse_url_parts
 
+                // Strip the fragment first so it never leaks into the last query value
+                let (__without_fragment, __fragment) = match input.find('#') {
+                    Some(idx) => (&input[..idx], Some(&input[idx + 1..]).filter(|f| !f.is_empty())),
+                    None => (input, None),
+                };
+
                 // Parse URL to get path and query params
-                let (path, query_params) = parse_url_parts(input);
-                let path_segments: Vec<&str> = path.trim_start_matches('/')
+                let (__path, __query_params) = parse_url_parts(__without_fragment);
+                let __path_segments: Vec<&str> = __path.trim_start_matches('/')
                     .split('/')
                     .filter(|s| !s.is_empty())
                     .collect();
 
+                // A matched path whose fields fail to parse falls through to the next variant,
+                // and its error is only reported when no later variant parses either
+                #[allow(unused_mut)]
+                let mut __first_error: Option<::leptos_routable::prelude::RouteParseError> = None;
+
                 #(#match_arms)*
 
-                Err(format!("No route matches path: {}", input))
+                Err(__first_error.unwrap_or_else(|| {
+                    ::leptos_routable::prelude::RouteParseError::NoMatch { path: input.to_string() }
+                }))
+            }
+        }
+
+        impl ::std::str::FromStr for #enum_ident {
+            type Err = ::leptos_routable::prelude::RouteParseError;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                <Self as ::leptos_routable::prelude::Routable>::parse_localized(input).map(|(route, _)| route)
             }
         }
     })
@@ -806,15 +1388,18 @@ fn generate_pattern_match(
 ) -> syn::Result<proc_macro2::TokenStream> {
     use crate::to_href_display::RouteSegment;
 
+    let variant_name = format!("{}::{}", enum_ident, variant_ident);
     let mut field_parsers = Vec::new();
     let mut required_segments = 0;
     let mut has_optional = false;
+    let mut has_wildcard = false;
 
     // Count required segments and check for optional params
     for seg in segments {
         match seg {
             RouteSegment::Static(_) | RouteSegment::Param(_) => required_segments += 1,
             RouteSegment::OptionalParam(_) => has_optional = true,
+            RouteSegment::Wildcard(_) => has_wildcard = true,
         }
     }
 
@@ -827,7 +1412,7 @@ fn generate_pattern_match(
         match seg {
             RouteSegment::Static(text) => {
                 segment_checks.push(quote! {
-                    if path_segments.get(#idx) != Some(&#text) {
+                    if !__path_segments.get(#idx).is_some_and(|s| ::leptos_routable::prelude::path_segment_matches(s, #text)) {
                         return false;
                     }
                 });
@@ -835,37 +1420,79 @@ fn generate_pattern_match(
             }
             RouteSegment::Param(name) => {
                 let field_ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+                let parse = parse_call(
+                    "param",
+                    named_field_type(fields, name)?,
+                    &variant_name,
+                    name,
+                    quote!(::leptos_routable::prelude::decode_path_segment(__path_segments[#idx])),
+                );
                 field_parsers.push(quote! {
-                    let #field_ident = path_segments[#idx]
-                        .parse()
-                        .map_err(|_| format!("Failed to parse {} as expected type", #name))?;
+                    let #field_ident = #parse?;
                 });
                 segment_idx += 1;
             }
             RouteSegment::OptionalParam(name) => {
                 let field_ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+                let parse = parse_call(
+                    "param",
+                    crate::to_href_display::inner_type(named_field_type(fields, name)?),
+                    &variant_name,
+                    name,
+                    quote!(s),
+                );
+                // An empty segment reads as `None`, like an empty `MaybeParam`
                 field_parsers.push(quote! {
-                    let #field_ident = path_segments.get(#idx)
-                        .and_then(|s| s.parse().ok());
+                    let #field_ident = __path_segments.get(#idx)
+                        .map(|s| ::leptos_routable::prelude::decode_path_segment(s))
+                        .filter(|s| !matches!(s, Ok(s) if s.is_empty()))
+                        .map(|s| #parse)
+                        .transpose()?;
                 });
                 segment_idx += 1;
             }
+            RouteSegment::Wildcard(name) => {
+                let field_ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+                let field_ty = named_field_type(fields, name)?;
+                // Consume every remaining segment greedily
+                let rest = quote! {
+                    __path_segments.get(#idx..).unwrap_or_default().iter()
+                        .map(|s| ::leptos_routable::prelude::decode_path_segment(s))
+                };
+                if crate::to_href_display::is_vec_type(field_ty) {
+                    let parse = parse_call(
+                        "param",
+                        crate::to_href_display::inner_type(field_ty),
+                        &variant_name,
+                        name,
+                        quote!(raw),
+                    );
+                    field_parsers.push(quote! {
+                        let #field_ident = #rest
+                            .map(|raw| #parse)
+                            .collect::<Result<_, _>>()?;
+                    });
+                } else {
+                    let parse = parse_call(
+                        "param",
+                        field_ty,
+                        &variant_name,
+                        name,
+                        quote!(#rest.collect::<Result<Vec<_>, _>>().map(|parts| parts.join("/"))),
+                    );
+                    field_parsers.push(quote! {
+                        let #field_ident = #parse?;
+                    });
+                }
+            }
         }
     }
 
     // Handle query parameters for optional fields
-    let query_param_parsers = generate_query_param_parsers(fields, segments);
+    let query_param_parsers = generate_query_param_parsers(fields, segments, &variant_name)?;
 
     // Get nested field type if this is a parent route with nested routes
-    let nested_field_ty = if let Fields::Unnamed(unnamed) = fields {
-        if unnamed.unnamed.len() == 1 {
-            Some(&unnamed.unnamed[0].ty)
-        } else {
-            None
-        }
-    } else {
-        None
-    };
+    let nested_field_ty = crate::field_attrs::nested_field(fields).map(|field| &field.ty);
 
     // Build the variant constructor
     let variant_constructor = build_variant_constructor(enum_ident, variant_ident, fields, segments, nested_field_ty)?;
@@ -876,12 +1503,12 @@ fn generate_pattern_match(
     let segment_count_val = syn::Index::from(segment_idx);
 
     // For nested routes, allow more segments than the parent path
-    let max_segments = if nested_field_ty.is_some() {
-        quote! { path_segments.len() >= #required_segments_val }
+    let max_segments = if nested_field_ty.is_some() || has_wildcard {
+        quote! { __path_segments.len() >= #required_segments_val }
     } else if has_optional {
-        quote! { path_segments.len() <= #max_segments_val }
+        quote! { __path_segments.len() <= #max_segments_val }
     } else {
-        quote! { path_segments.len() == #required_segments_val }
+        quote! { __path_segments.len() == #required_segments_val }
     };
 
     // For nested routes, include the parsing logic before the constructor
@@ -889,7 +1516,7 @@ fn generate_pattern_match(
         Ok(quote! {
             // Check if this route matches
             let matches = || -> bool {
-                if path_segments.len() < #required_segments_val {
+                if __path_segments.len() < #required_segments_val {
                     return false;
                 }
                 if !(#max_segments) {
@@ -900,17 +1527,23 @@ fn generate_pattern_match(
             };
 
             if matches() {
-                let segment_count = #segment_count_val;
-                #(#field_parsers)*
-                #query_param_parsers
-                #variant_constructor
+                let __parsed = (|| -> Result<Self, ::leptos_routable::prelude::RouteParseError> {
+                    let segment_count = #segment_count_val;
+                    #(#field_parsers)*
+                    #query_param_parsers
+                    #variant_constructor
+                })();
+                match __parsed {
+                    Ok(route) => return Ok(route),
+                    Err(err) => { __first_error.get_or_insert(err); }
+                }
             }
         })
     } else {
         Ok(quote! {
             // Check if this route matches
             let matches = || -> bool {
-                if path_segments.len() < #required_segments_val {
+                if __path_segments.len() < #required_segments_val {
                     return false;
                 }
                 if !(#max_segments) {
@@ -921,10 +1554,16 @@ fn generate_pattern_match(
             };
 
             if matches() {
-                let segment_count = #segment_count_val;
-                #(#field_parsers)*
-                #query_param_parsers
-                return Ok(#variant_constructor);
+                let __parsed = (|| -> Result<Self, ::leptos_routable::prelude::RouteParseError> {
+                    let segment_count = #segment_count_val;
+                    #(#field_parsers)*
+                    #query_param_parsers
+                    Ok(#variant_constructor)
+                })();
+                match __parsed {
+                    Ok(route) => return Ok(route),
+                    Err(err) => { __first_error.get_or_insert(err); }
+                }
             }
         })
     }
@@ -933,13 +1572,15 @@ fn generate_pattern_match(
 fn generate_query_param_parsers(
     fields: &Fields,
     segments: &[crate::to_href_display::RouteSegment],
-) -> proc_macro2::TokenStream {
+    variant_name: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
     // Collect field names used in path
     let mut used_fields = std::collections::HashSet::new();
     for seg in segments {
         match seg {
             crate::to_href_display::RouteSegment::Param(name) |
-            crate::to_href_display::RouteSegment::OptionalParam(name) => {
+            crate::to_href_display::RouteSegment::OptionalParam(name) |
+            crate::to_href_display::RouteSegment::Wildcard(name) => {
                 used_fields.insert(name.clone());
             }
             _ => {}
@@ -958,17 +1599,131 @@ fn generate_query_param_parsers(
                 continue;
             }
 
-            // Only handle Option fields in query params
-            if crate::to_href_display::is_option_type(&field.ty) {
+            let attrs = crate::field_attrs::FieldAttrs::from_field(field)?;
+            if attrs.is_nested() {
+                continue;
+            }
+            if attrs.is_fragment() {
+                let parse = parse_call(
+                    "fragment",
+                    crate::to_href_display::inner_type(&field.ty),
+                    variant_name,
+                    &field_name_str,
+                    quote!(::leptos_routable::prelude::decode_fragment(v)),
+                );
                 parsers.push(quote! {
-                    let #field_name = query_params.get(#field_name_str)
-                        .and_then(|v| v.parse().ok());
+                    let #field_name = __fragment
+                        .map(|v| #parse)
+                        .transpose()?;
                 });
+                continue;
             }
+            parsers.push(query_parse_tokens(field_name, &field.ty, &attrs, variant_name, attrs.is_query()));
         }
     }
 
-    quote! { #(#parsers)* }
+    Ok(quote! { #(#parsers)* })
+}
+
+/// Tokens binding one query field from `__query_params`, reporting errors against `owner`.
+pub(crate) fn query_parse_tokens(
+    field_name: &syn::Ident,
+    ty: &syn::Type,
+    attrs: &crate::field_attrs::FieldAttrs,
+    owner: &str,
+    required: bool,
+) -> proc_macro2::TokenStream {
+    let field_name_str = field_name.to_string();
+    let key = attrs.query_key(&field_name_str);
+
+    // Raw values are still percent-encoded; the last one wins for single-valued fields
+    let last_value = quote! {
+        __query_params.get(#key)
+            .and_then(|values| values.last())
+            .map(|v| ::leptos_routable::prelude::decode_query_component(v))
+    };
+
+    if attrs.is_flatten() {
+        quote! {
+            let #field_name = <#ty as ::leptos_routable::prelude::RouteQuery>::from_query(&__query_params)?;
+        }
+    } else if crate::to_href_display::is_collection_type(ty) {
+        let split = match attrs.query_separator() {
+            Some(sep) => quote! { .flat_map(|v| v.split(#sep)).filter(|v| !v.is_empty()) },
+            None => quote! {},
+        };
+        let parse = parse_call(
+            "query",
+            crate::to_href_display::inner_type(ty),
+            owner,
+            &field_name_str,
+            quote!(::leptos_routable::prelude::decode_query_component(v)),
+        );
+        quote! {
+            let #field_name = __query_params.get(#key)
+                .map(|values| values.as_slice())
+                .unwrap_or_default()
+                .iter()
+                #split
+                .map(|v| #parse)
+                .collect::<Result<_, _>>()?;
+        }
+    } else if attrs.is_return_to() {
+        // A stale or foreign `return_to` is dropped instead of failing the whole route
+        quote! {
+            let #field_name = #last_value.and_then(Result::ok).and_then(|v| v.parse().ok());
+        }
+    } else if crate::to_href_display::is_option_type(ty) {
+        let parse = parse_call("query", crate::to_href_display::inner_type(ty), owner, &field_name_str, quote!(v));
+        // An empty value reads as `None`, like an empty `MaybeQuery`
+        quote! {
+            let #field_name = #last_value
+                .filter(|v| !matches!(v, Ok(v) if v.is_empty()))
+                .map(|v| #parse)
+                .transpose()?;
+        }
+    } else if required {
+        let parse = parse_call("query", ty, owner, &field_name_str, quote!(v));
+        quote! {
+            let #field_name = match #last_value {
+                Some(v) => #parse?,
+                None => return Err(::leptos_routable::prelude::RouteParseError::MissingQuery {
+                    variant: #owner,
+                    field: #field_name_str,
+                    key: #key,
+                }),
+            };
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// A `RouteParseError::parse_<kind>` call parsing the `decoded` value into `ty`.
+///
+/// The type is spelled out so the error source dispatch sees the concrete `FromStr::Err`.
+fn parse_call(kind: &str, ty: &syn::Type, owner: &str, field: &str, decoded: TokenStream2) -> TokenStream2 {
+    let parse = quote::format_ident!("parse_{}", kind);
+    quote! {
+        ::leptos_routable::prelude::RouteParseError::#parse::<#ty>(
+            #owner,
+            #field,
+            #decoded,
+            |e| {
+                use ::leptos_routable::prelude::IntoErrorSource as _;
+                ::leptos_routable::prelude::ErrorSource(e).into_error_source()
+            },
+        )
+    }
+}
+
+/// The type of the named field a path param binds to.
+fn named_field_type<'a>(fields: &'a Fields, name: &str) -> syn::Result<&'a syn::Type> {
+    fields
+        .iter()
+        .find(|f| f.ident.as_ref().is_some_and(|i| i == name))
+        .map(|f| &f.ty)
+        .ok_or_else(|| syn::Error::new(fields.span(), format!("No field named `{name}` for path param `:{name}`.")))
 }
 
 fn build_variant_constructor(
@@ -978,6 +1733,29 @@ fn build_variant_constructor(
     segments: &[crate::to_href_display::RouteSegment],
     nested_field_ty: Option<&syn::Type>,
 ) -> syn::Result<proc_macro2::TokenStream> {
+    // For nested routes (parent routes), we need to parse the remaining path into `nested`
+    let parse_nested = nested_field_ty.map(|field_ty| {
+        let variant_name = format!("{}::{}", enum_ident, variant_ident);
+        quote! {
+            // Construct the remaining path for nested route
+            let remaining_path = if __path_segments.len() > segment_count {
+                let remaining: Vec<&str> = __path_segments[segment_count..].to_vec();
+                format!("/{}", remaining.join("/"))
+            } else {
+                "/".to_string()
+            };
+            // Carry the (still encoded) query string and fragment through to the nested enum
+            let remaining_path = format!("{}{}", remaining_path, &input[__path.len()..]);
+
+            // Parse the nested route in the locale this one matched under
+            let nested = <#field_ty as ::leptos_routable::prelude::Routable>::parse_in_locale(&remaining_path, __locale)
+                .map_err(|inner| ::leptos_routable::prelude::RouteParseError::Nested {
+                    parent: #variant_name,
+                    inner: Box::new(inner),
+                })?;
+        }
+    });
+
     match fields {
         Fields::Unit => Ok(quote! { #enum_ident::#variant_ident }),
         Fields::Named(named) => {
@@ -986,18 +1764,25 @@ fn build_variant_constructor(
             for field in &named.named {
                 let field_name = field.ident.as_ref().unwrap();
                 let field_name_str = field_name.to_string();
+                let attrs = crate::field_attrs::FieldAttrs::from_field(field)?;
 
                 // Check if field is used in path
                 let in_path = segments.iter().any(|seg| match seg {
                     crate::to_href_display::RouteSegment::Param(name) |
-                    crate::to_href_display::RouteSegment::OptionalParam(name) => name == &field_name_str,
+                    crate::to_href_display::RouteSegment::OptionalParam(name) |
+                    crate::to_href_display::RouteSegment::Wildcard(name) => name == &field_name_str,
                     _ => false,
                 });
 
                 if in_path {
                     field_inits.push(quote! { #field_name });
-                } else if crate::to_href_display::is_option_type(&field.ty) {
-                    // Query param field (should be Option)
+                } else if attrs.is_nested() {
+                    field_inits.push(quote! { #field_name: nested });
+                } else if crate::to_href_display::is_option_type(&field.ty)
+                    || crate::to_href_display::is_collection_type(&field.ty)
+                    || attrs.is_query()
+                {
+                    // Query param field (Option, collection or explicit `#[query]`)
                     field_inits.push(quote! { #field_name });
                 } else {
                     // Non-Option field not in path - this shouldn't happen with proper validation
@@ -1005,26 +1790,22 @@ fn build_variant_constructor(
                 }
             }
 
-            Ok(quote! { #enum_ident::#variant_ident { #(#field_inits),* } })
+            match parse_nested {
+                Some(parse_nested) => Ok(quote! {
+                    {
+                        #parse_nested
+                        return Ok(#enum_ident::#variant_ident { #(#field_inits),* });
+                    }
+                }),
+                None => Ok(quote! { #enum_ident::#variant_ident { #(#field_inits),* } }),
+            }
         }
         Fields::Unnamed(unnamed) => {
             if unnamed.unnamed.len() == 1 {
-                // For nested routes (parent routes), we need to parse the remaining path
-                if let Some(field_ty) = nested_field_ty {
+                if let Some(parse_nested) = parse_nested {
                     Ok(quote! {
                         {
-                            // Construct the remaining path for nested route
-                            let remaining_path = if path_segments.len() > segment_count {
-                                let remaining: Vec<&str> = path_segments[segment_count..].to_vec();
-                                format!("/{}", remaining.join("/"))
-                            } else {
-                                "/".to_string()
-                            };
-
-                            // Parse nested route using FromStr
-                            let nested = <#field_ty as ::std::str::FromStr>::from_str(&remaining_path)
-                                .map_err(|_| format!("Failed to parse nested route at path: {}", input))?;
-
+                            #parse_nested
                             return Ok(#enum_ident::#variant_ident(nested));
                         }
                     })
@@ -1047,26 +1828,11 @@ fn build_variant_constructor(
 // Helper function to parse URL into path and query params
 fn parse_url_parts_tokens() -> proc_macro2::TokenStream {
     quote! {
-        fn parse_url_parts(url: &str) -> (&str, std::collections::HashMap<String, String>) {
-            let mut query_params = std::collections::HashMap::new();
-
-            let (path, query) = if let Some(idx) = url.find('?') {
-                (&url[..idx], Some(&url[idx + 1..]))
-            } else {
-                (url, None)
-            };
-
-            if let Some(query_str) = query {
-                for pair in query_str.split('&') {
-                    if let Some(eq_idx) = pair.find('=') {
-                        let key = &pair[..eq_idx];
-                        let value = &pair[eq_idx + 1..];
-                        query_params.insert(key.to_string(), value.to_string());
-                    }
-                }
+        fn parse_url_parts(url: &str) -> (&str, ::leptos_routable::prelude::RawQueryParams) {
+            match url.find('?') {
+                Some(idx) => (&url[..idx], ::leptos_routable::prelude::parse_query_string(&url[idx + 1..])),
+                None => (url, ::leptos_routable::prelude::RawQueryParams::new()),
             }
-
-            (path, query_params)
         }
     }
 }
@@ -1154,45 +1920,43 @@ fn generate_recursive_provides(
             );
         });
 
-        if let syn::Fields::Unnamed(fields) = &variant.fields {
-            if let Some(syn::Field { ty: syn::Type::Path(type_path), .. }) = fields.unnamed.first() {
-                if let Some(nested_enum) = type_path.path.segments.last() {
-                    let nested_enum_ident = &nested_enum.ident;
+        if let Some(syn::Field { ty: syn::Type::Path(type_path), .. }) = crate::field_attrs::nested_field(&variant.fields) {
+            if let Some(nested_enum) = type_path.path.segments.last() {
+                let nested_enum_ident = &nested_enum.ident;
 
-                    let sub_state_type = crate::utils::build_module_state_path(
-                        &variant.ident,
-                        true,
-                        module_prefix
-                    );
-
-                    // Build trait path for variant's state
-                    let variant_snake = to_snake_case(&variant.ident.to_string());
-                    let variant_state_trait: TokenStream2 = format!(
-                        "crate::{}::{}::state::StateStoreFields",
-                        accessor_state_module,
-                        variant_snake
-                    ).parse().unwrap();
-
-                    statements.push(quote! {
-                        {
-                            use #variant_state_trait;
-                            leptos::prelude::provide_context(
-                                reactive_stores::Field::<#sub_state_type>::from(
-                                    #accessor.clone().#field_name().sub_state()
-                                )
-                            );
-                        }
-                    });
+                let sub_state_type = crate::utils::build_module_state_path(
+                    &variant.ident,
+                    true,
+                    module_prefix
+                );
 
-                    statements.push(quote! {
-                        {
-                            use #variant_state_trait;
-                            #nested_enum_ident::__provide_contexts(
+                // Build trait path for variant's state
+                let variant_snake = to_snake_case(&variant.ident.to_string());
+                let variant_state_trait: TokenStream2 = format!(
+                    "crate::{}::{}::state::StateStoreFields",
+                    accessor_state_module,
+                    variant_snake
+                ).parse().unwrap();
+
+                statements.push(quote! {
+                    {
+                        use #variant_state_trait;
+                        leptos::prelude::provide_context(
+                            reactive_stores::Field::<#sub_state_type>::from(
                                 #accessor.clone().#field_name().sub_state()
-                            );
-                        }
-                    });
-                }
+                            )
+                        );
+                    }
+                });
+
+                statements.push(quote! {
+                    {
+                        use #variant_state_trait;
+                        #nested_enum_ident::__provide_contexts(
+                            #accessor.clone().#field_name().sub_state()
+                        );
+                    }
+                });
             }
         }
     }
@@ -1204,13 +1968,42 @@ fn generate_recursive_provides(
  * Parse Route Kind
  * -----------------------------------------------------------------------------------------------*/
 fn parse_variant(variant: &syn::Variant) -> Result<Option<RouteKind>, darling::Error> {
-    Ok(try_parse_variants!(
+    let kind = try_parse_variants!(
         variant,
         RouteVariant,
         ParentRouteVariant,
         ProtectedRouteVariant,
         ProtectedParentRouteVariant
-    ))
+    );
+    let nested = crate::field_attrs::nested_field(&variant.fields);
+    match (&kind, nested) {
+        (Some(RouteKind::ParentRoute(_) | RouteKind::ProtectedParentRoute(_)), None) => {
+            return Err(syn::Error::new(
+                variant.span(),
+                "Parent routes need a nested route enum: a single unnamed field or a named field marked `#[nested]`.",
+            ).into());
+        }
+        (Some(RouteKind::Route(_) | RouteKind::ProtectedRoute(_)), Some(field)) if field.ident.is_some() => {
+            return Err(syn::Error::new(
+                field.span(),
+                "`#[nested]` fields are only allowed on `#[parent_route]` and `#[protected_parent_route]` variants.",
+            ).into());
+        }
+        _ => {}
+    }
+    if let Some(loading) = kind.as_ref().filter(|kind| kind.lazy().is_none()).and_then(RouteKind::loading) {
+        return Err(syn::Error::new(
+            loading.span(),
+            "`loading` only applies to `lazy` routes.",
+        ).into());
+    }
+    if let Some(RouteKind::Route(RouteVariant { options: RouteOptions { ssr: Some(ssr), .. }, static_params: Some(_), .. })) = &kind {
+        return Err(syn::Error::new(
+            ssr.span(),
+            "`ssr` cannot be combined with `static_params`, which always renders with `SsrMode::Static`.",
+        ).into());
+    }
+    Ok(kind)
 }
 
 fn multiple_route_error(variant: &syn::Variant) -> darling::Error {
@@ -0,0 +1,91 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields};
+use crate::field_attrs::FieldAttrs;
+
+/* -------------------------------------------------------------------------------------------------
+ * `#[derive(RouteQuery)]` implementation
+ * -----------------------------------------------------------------------------------------------*/
+pub fn derive_route_query_impl(input: TokenStream) -> TokenStream {
+    let input_ast = parse_macro_input!(input as DeriveInput);
+    match generate_route_query_impl(&input_ast) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn generate_route_query_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "`#[derive(RouteQuery)]` requires a struct with named fields.",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "`#[derive(RouteQuery)]` can only be used on structs.",
+            ))
+        }
+    };
+
+    let owner = ident.to_string();
+    let mut pushers = Vec::new();
+    let mut parsers = Vec::new();
+    let mut field_names = Vec::new();
+
+    // Every field is a query param; anything that isn't `Option<T>` or a collection is required
+    for field in &named.named {
+        let field_name = field.ident.as_ref().unwrap();
+        let attrs = FieldAttrs::from_field(field)?;
+        pushers.push(crate::to_href_display::query_push_tokens(
+            &quote!(self.#field_name),
+            &field.ty,
+            &attrs,
+            &field_name.to_string(),
+            true,
+        ));
+        parsers.push(crate::derive_routable::query_parse_tokens(
+            field_name,
+            &field.ty,
+            &attrs,
+            &owner,
+            true,
+        ));
+        field_names.push(field_name);
+    }
+
+    Ok(quote! {
+        impl ::leptos_routable::prelude::RouteQuery for #ident {
+            fn append_query(&self, __query_vec: &mut Vec<(String, String)>) {
+                #(#pushers)*
+            }
+
+            fn from_query(
+                __query_params: &::leptos_routable::prelude::RawQueryParams,
+            ) -> Result<Self, ::leptos_routable::prelude::RouteParseError> {
+                #(#parsers)*
+                Ok(Self { #(#field_names),* })
+            }
+        }
+
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(&::leptos_routable::prelude::RouteQuery::to_query_string(self))
+            }
+        }
+
+        impl ::std::str::FromStr for #ident {
+            type Err = ::leptos_routable::prelude::RouteParseError;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                <Self as ::leptos_routable::prelude::RouteQuery>::from_query_string(input)
+            }
+        }
+    })
+}
@@ -0,0 +1,182 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Field, Ident, LitStr};
+
+/// Options from a field-level `#[query]` / `#[query(rename = "...", separator = ",")]` attribute.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct QueryOptions {
+    pub(crate) rename: Option<String>,
+    /// Joins collection values into a single key instead of repeating the key.
+    pub(crate) separator: Option<char>,
+    /// Delegates encoding and decoding to a `RouteQuery` struct.
+    pub(crate) flatten: bool,
+    /// Receives the route a guard or protected-route redirect came from, under `return_to`.
+    pub(crate) return_to: bool,
+}
+
+/// Route-related attributes that may appear on a variant's named fields.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FieldAttrs {
+    pub(crate) query: Option<QueryOptions>,
+    /// Field is rendered as, and parsed from, the URL `#fragment`.
+    pub(crate) fragment: bool,
+    /// Field holds the nested route enum of a parent route with path params.
+    pub(crate) nested: bool,
+}
+
+impl FieldAttrs {
+    pub(crate) fn from_field(field: &Field) -> syn::Result<Self> {
+        let mut out = Self::default();
+        // Conflicts are reported on the offending attribute itself
+        let (mut query_attr, mut fragment_attr, mut nested_attr) = (None, None, None);
+        for attr in &field.attrs {
+            if attr.path().is_ident("query") {
+                query_attr = Some(attr);
+                let mut options = QueryOptions::default();
+                // Bare `#[query]` carries no arguments
+                if !matches!(attr.meta, syn::Meta::Path(_)) {
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("rename") {
+                            options.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                            Ok(())
+                        } else if meta.path.is_ident("flatten") {
+                            options.flatten = true;
+                            Ok(())
+                        } else if meta.path.is_ident("return_to") {
+                            options.return_to = true;
+                            Ok(())
+                        } else if meta.path.is_ident("separator") {
+                            let lit = meta.value()?.parse::<LitStr>()?;
+                            options.separator = Some(parse_separator(&lit)?);
+                            Ok(())
+                        } else {
+                            Err(meta.error(
+                                "unsupported `#[query]` argument, expected `rename`, `separator`, `flatten` or `return_to`",
+                            ))
+                        }
+                    })?;
+                    if options.flatten && (options.rename.is_some() || options.separator.is_some()) {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "`#[query(flatten)]` cannot be combined with `rename` or `separator`",
+                        ));
+                    }
+                    if options.return_to && (options.flatten || options.rename.is_some() || options.separator.is_some()) {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "`#[query(return_to)]` cannot be combined with `rename`, `separator` or `flatten`",
+                        ));
+                    }
+                    if options.return_to && !crate::to_href_display::is_option_type(&field.ty) {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "`#[query(return_to)]` only applies to `Option<ReturnTo<_>>` fields",
+                        ));
+                    }
+                }
+                out.query = Some(options);
+            } else if attr.path().is_ident("fragment") {
+                attr.meta.require_path_only()?;
+                out.fragment = true;
+                fragment_attr = Some(attr);
+            } else if attr.path().is_ident("nested") {
+                attr.meta.require_path_only()?;
+                out.nested = true;
+                nested_attr = Some(attr);
+            }
+        }
+        if let Some(query_attr) = query_attr.filter(|_| out.query_separator().is_some()) {
+            if !crate::to_href_display::is_collection_type(&field.ty) {
+                return Err(syn::Error::new_spanned(
+                    query_attr,
+                    "`separator` only applies to `Vec`, `HashSet` and `BTreeSet` fields",
+                ));
+            }
+        }
+        if let Some(fragment_attr) = fragment_attr.filter(|_| out.query.is_some()) {
+            return Err(syn::Error::new_spanned(
+                fragment_attr,
+                "A field cannot be both `#[query]` and `#[fragment]`",
+            ));
+        }
+        if let Some(nested_attr) = nested_attr.filter(|_| out.fragment || out.query.is_some()) {
+            return Err(syn::Error::new_spanned(
+                nested_attr,
+                "A `#[nested]` field cannot also be `#[query]` or `#[fragment]`",
+            ));
+        }
+        Ok(out)
+    }
+
+    /// Returns the key used for this field in the query string.
+    pub(crate) fn query_key(&self, field_name: &str) -> String {
+        if self.is_return_to() {
+            // Must match `leptos_routable::prelude::RETURN_TO_KEY`
+            return "return_to".to_string();
+        }
+        self.query
+            .as_ref()
+            .and_then(|q| q.rename.clone())
+            .unwrap_or_else(|| field_name.to_string())
+    }
+
+    /// `true` if the field was explicitly marked with `#[query]`.
+    pub(crate) fn is_query(&self) -> bool {
+        self.query.is_some()
+    }
+
+    pub(crate) fn is_fragment(&self) -> bool {
+        self.fragment
+    }
+
+    pub(crate) fn is_nested(&self) -> bool {
+        self.nested
+    }
+
+    pub(crate) fn is_flatten(&self) -> bool {
+        self.query.as_ref().is_some_and(|q| q.flatten)
+    }
+
+    pub(crate) fn is_return_to(&self) -> bool {
+        self.query.as_ref().is_some_and(|q| q.return_to)
+    }
+
+    pub(crate) fn query_separator(&self) -> Option<char> {
+        self.query.as_ref().and_then(|q| q.separator)
+    }
+}
+
+/// The field holding a parent variant's nested route enum: its unnamed field, or the named
+/// field marked `#[nested]`.
+pub(crate) fn nested_field<'a>(fields: impl IntoIterator<Item = &'a Field>) -> Option<&'a Field> {
+    fields
+        .into_iter()
+        .find(|f| f.ident.is_none() || f.attrs.iter().any(|attr| attr.path().is_ident("nested")))
+}
+
+/// The name `to_href_display`'s variant patterns bind `field` to: its own name, `_0` when unnamed.
+pub(crate) fn nested_binding(field: &Field) -> Ident {
+    field.ident.clone().unwrap_or_else(|| Ident::new("_0", proc_macro2::Span::call_site()))
+}
+
+/// `Enum::Variant(binding, ..)` or `Enum::Variant { field: binding, .. }` for the nested `field`.
+pub(crate) fn nested_pattern(enum_ident: &Ident, variant_ident: &Ident, field: &Field, binding: &Ident) -> TokenStream2 {
+    match &field.ident {
+        Some(ident) => quote! { #enum_ident::#variant_ident { #ident: #binding, .. } },
+        None => quote! { #enum_ident::#variant_ident(#binding, ..) },
+    }
+}
+
+/// The separator must be a single reserved character; unreserved ones are never
+/// percent-encoded and would be ambiguous with the values themselves.
+fn parse_separator(lit: &LitStr) -> syn::Result<char> {
+    let value = lit.value();
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_punctuation() && !matches!(c, '-' | '.' | '_' | '~' | '&' | '=' | '#') => Ok(c),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            "`separator` must be a single reserved character such as \",\", \"|\" or \";\"",
+        )),
+    }
+}
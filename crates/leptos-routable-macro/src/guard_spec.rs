@@ -0,0 +1,72 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{spanned::Spanned, Expr};
+
+/// The value of a `guard = ...` argument: a `RouteGuard` type, `none`, or an
+/// `all(...)`/`any(...)` combination of guards.
+#[derive(Debug, Clone)]
+pub(crate) enum GuardSpec {
+    None,
+    Guard(syn::Path),
+    All(Vec<GuardSpec>),
+    Any(Vec<GuardSpec>),
+}
+
+impl GuardSpec {
+    /// The guard type to render through, `None` for `guard = none`.
+    pub(crate) fn to_type_tokens(&self) -> Option<TokenStream2> {
+        match self {
+            Self::None => None,
+            Self::Guard(path) => Some(quote! { #path }),
+            Self::All(guards) => Some(fold_guards(guards, quote! { ::leptos_routable::prelude::AllOf })),
+            Self::Any(guards) => Some(fold_guards(guards, quote! { ::leptos_routable::prelude::AnyOf })),
+        }
+    }
+
+    fn from_expr_inner(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Path(path) if path.path.is_ident("none") => Ok(Self::None),
+            Expr::Path(path) => Ok(Self::Guard(path.path.clone())),
+            Expr::Call(call) => {
+                let Expr::Path(func) = &*call.func else {
+                    return Err(darling::Error::custom("expected `all(...)` or `any(...)`").with_span(&call.func));
+                };
+                let guards = call
+                    .args
+                    .iter()
+                    .map(Self::from_expr_inner)
+                    .collect::<darling::Result<Vec<_>>>()?;
+                if guards.is_empty() || guards.iter().any(|g| matches!(g, Self::None)) {
+                    return Err(darling::Error::custom("guard combinators need at least one guard and cannot contain `none`")
+                        .with_span(&call.span()));
+                }
+                if func.path.is_ident("all") {
+                    Ok(Self::All(guards))
+                } else if func.path.is_ident("any") {
+                    Ok(Self::Any(guards))
+                } else {
+                    Err(darling::Error::custom("expected `all(...)` or `any(...)`").with_span(&func.span()))
+                }
+            }
+            Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) => {
+                Self::from_expr_inner(&lit.parse::<Expr>()?)
+            }
+            Expr::Group(group) => Self::from_expr_inner(&group.expr),
+            _ => Err(darling::Error::unexpected_expr_type(expr)),
+        }
+    }
+}
+
+impl FromMeta for GuardSpec {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        Self::from_expr_inner(expr)
+    }
+}
+
+/// `all(a, b, c)` becomes `AllOf<a, AllOf<b, c>>`.
+fn fold_guards(guards: &[GuardSpec], combinator: TokenStream2) -> TokenStream2 {
+    let mut types = guards.iter().rev().filter_map(GuardSpec::to_type_tokens);
+    let last = types.next().unwrap_or_default();
+    types.fold(last, |acc, guard| quote! { #combinator<#guard, #acc> })
+}
@@ -0,0 +1,48 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+/// The split point of a `lazy` variant's view and the route view rendering it.
+///
+/// The split point always goes through `#[leptos::lazy]`, which decides what it becomes:
+/// with leptos' `csr` or `hydrate` feature it loads from its own wasm chunk, otherwise it
+/// resolves at once and, on the server, records the chunk so hydration preloads it.
+pub(crate) fn split_view(
+    enum_ident: &Ident,
+    variant_ident: &Ident,
+    view_path: &TokenStream2,
+    loading: Option<&syn::Expr>,
+) -> (TokenStream2, TokenStream2) {
+    let split_ident = split_ident(enum_ident, variant_ident);
+    let item = quote! {
+        #[allow(non_snake_case)]
+        #[::leptos::lazy]
+        fn #split_ident() -> ::leptos::prelude::AnyView {
+            ::leptos::prelude::IntoAny::into_any((#view_path)())
+        }
+    };
+    let loading = loading.map_or_else(|| quote! { || () }, |loading| quote! { #loading });
+    let view = quote! {
+        move || ::leptos_routable::prelude::lazy_view(#loading, #split_ident)
+    };
+    (item, view)
+}
+
+fn split_ident(enum_ident: &Ident, variant_ident: &Ident) -> Ident {
+    format_ident!("__{}_{}_lazy_view", enum_ident, variant_ident)
+}
+
+/// Starts fetching the chunk of a `lazy` variant's view, through the `__preload_` function
+/// `#[leptos::lazy]` generates next to the split point.
+///
+/// That function only exists when leptos splits chunks, so this checks the same condition
+/// as leptos: its `csr` or `hydrate` feature, which `leptos-routable` forwards to this crate.
+pub(crate) fn preload_chunk(enum_ident: &Ident, variant_ident: &Ident) -> TokenStream2 {
+    if !(cfg!(feature = "csr") || cfg!(feature = "hydrate")) {
+        return TokenStream2::new();
+    }
+    let preload_ident = format_ident!("__preload_{}", split_ident(enum_ident, variant_ident));
+    quote! {
+        ::leptos::task::spawn_local(#preload_ident());
+    }
+}
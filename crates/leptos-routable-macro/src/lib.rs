@@ -1,17 +1,35 @@
 #![allow(clippy::needless_return)]
 extern crate proc_macro;
+pub(crate) mod derive_route_query;
 pub(crate) mod derive_routable;
+pub(crate) mod field_attrs;
+pub(crate) mod guard_spec;
+pub(crate) mod lazy;
+pub(crate) mod localized;
+pub(crate) mod prefetch;
+pub(crate) mod redirect;
+pub(crate) mod route_metadata;
+pub(crate) mod route_table;
 pub(crate) mod to_href_display;
 pub(crate) mod utils;
 
 #[proc_macro_derive(Routable, attributes(
     route,
     fallback,
+    query,
+    fragment,
+    nested,
     routes,
     protected_route,
     parent_route,
-    protected_parent_route
+    protected_parent_route,
+    redirect
 ))]
 pub fn derive_routable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     derive_routable::derive_routable_impl(input)
 }
+
+#[proc_macro_derive(RouteQuery, attributes(query))]
+pub fn derive_route_query(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_route_query::derive_route_query_impl(input)
+}
@@ -0,0 +1,271 @@
+use darling::ast::NestedMeta;
+use darling::FromMeta;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Attribute, Fields, Ident, LitStr, Meta};
+
+/// `path_<locale> = "..."` arguments of a route-like attribute, e.g. `path_de = "/einstellungen"`.
+/// Underscores in the locale stand for dashes, so `path_pt_br` is the path of `pt-br`.
+#[derive(std::fmt::Debug, Default)]
+pub(crate) struct LocalizedPaths {
+    pub(crate) paths: Vec<(String, LitStr)>,
+}
+
+impl FromMeta for LocalizedPaths {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+        let mut paths = Vec::new();
+        for item in items {
+            let NestedMeta::Meta(meta) = item else {
+                errors.push(darling::Error::unsupported_format("literal").with_span(item));
+                continue;
+            };
+            let name = meta.path().get_ident().map(ToString::to_string).unwrap_or_default();
+            match (name.strip_prefix("path_"), meta) {
+                (Some(locale), Meta::NameValue(_)) => {
+                    if let Some(path) = errors.handle(LitStr::from_meta(meta)) {
+                        paths.push((locale_name(locale), path));
+                    }
+                }
+                _ => errors.push(darling::Error::unknown_field_path(meta.path()).with_span(meta)),
+            }
+        }
+        errors.finish_with(Self { paths })
+    }
+}
+
+fn locale_name(key: &str) -> String {
+    key.replace('_', "-")
+}
+
+/// The `path_<locale>` arguments of a variant's route-like attribute, read from the raw
+/// attributes like [`find_route_path`](crate::to_href_display::find_route_path).
+pub(crate) fn find_localized_paths(attrs: &[Attribute]) -> Vec<(String, LitStr)> {
+    let mut paths = Vec::new();
+    for attr in attrs {
+        if attr.path().is_ident("route")
+            || attr.path().is_ident("parent_route")
+            || attr.path().is_ident("protected_route")
+            || attr.path().is_ident("protected_parent_route")  {
+            let _ = attr.parse_nested_meta(|meta| {
+                let name = meta.path.get_ident().map(ToString::to_string).unwrap_or_default();
+                match name.strip_prefix("path_") {
+                    Some(locale) => paths.push((locale_name(locale), meta.value()?.parse::<LitStr>()?)),
+                    None => crate::to_href_display::skip_meta_value(&meta)?,
+                }
+                Ok(())
+            });
+        }
+    }
+    paths
+}
+
+/// Checks the localized paths of one variant against `#[routes(locales = [...])]` and its canonical path.
+pub(crate) fn validate(locales: &[String], path: &str, localized: &[(String, LitStr)]) -> syn::Result<()> {
+    for (locale, localized_path) in localized {
+        if !locales.is_empty() && !locales.iter().any(|l| l.eq_ignore_ascii_case(locale)) {
+            return Err(syn::Error::new(
+                localized_path.span(),
+                format!("locale `{locale}` is not listed in `#[routes(locales = [...])]`"),
+            ));
+        }
+    }
+    let paths: Vec<_> = localized.iter().map(|(_, path)| path.clone()).collect();
+    crate::to_href_display::validate_aliases(path, &paths)
+}
+
+/// The router path of a route path. `leptos_router::path!` only takes ASCII static segments
+/// and matches them against the percent-encoded pathname, so paths with non-ASCII text, such
+/// as a translated `/über-uns`, register their static segments percent-encoded instead.
+pub(crate) fn router_path(path: &LitStr) -> TokenStream2 {
+    let value = path.value();
+    if value.is_ascii() {
+        return quote! { ::leptos_router::path!(#path) };
+    }
+    // The segments `path!` would produce
+    let mut segments: Vec<TokenStream2> = value
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| match (segment.strip_prefix(':'), segment.strip_prefix('*')) {
+            (Some(param), _) => match param.strip_suffix('?') {
+                Some(param) => quote! { ::leptos_router::OptionalParamSegment(#param) },
+                None => quote! { ::leptos_router::ParamSegment(#param) },
+            },
+            (_, Some(wildcard)) => quote! { ::leptos_router::WildcardSegment(#wildcard) },
+            _ => {
+                let encoded = percent_encode_non_ascii(segment);
+                quote! { ::leptos_router::StaticSegment(#encoded) }
+            }
+        })
+        .collect();
+    if value.len() > 1 && value.ends_with('/') {
+        segments.push(quote! { ::leptos_router::StaticSegment("/") });
+    }
+    quote! { (#(#segments,)*) }
+}
+
+fn percent_encode_non_ascii(text: &str) -> String {
+    let mut encoded = String::new();
+    for c in text.chars() {
+        match c.is_ascii() {
+            true => encoded.push(c),
+            false => {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    encoded.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+    encoded
+}
+
+/// A path the router registers a variant under.
+pub(crate) struct RegisteredPath {
+    pub(crate) path: LitStr,
+    /// The locale a parent route passes on to its nested enum: `Some` for `/{locale}` prefixed
+    /// paths, otherwise the locale the enum itself was registered under.
+    pub(crate) locale: TokenStream2,
+    /// The check on `__locale` a nested enum registers the path under, `None` to always register it.
+    pub(crate) check: Option<TokenStream2>,
+}
+
+/// Every path to register a variant under, its canonical `path` first.
+///
+/// Enums declaring `locales` register each localized path behind its locale prefix. Enums
+/// without them register their paths unprefixed, limited to the locale of the parent route
+/// they are nested under, the same way they parse.
+pub(crate) fn registered_paths(
+    locales: &[String],
+    path: &LitStr,
+    aliases: &[LitStr],
+    localized: &[(String, LitStr)],
+) -> Vec<RegisteredPath> {
+    let lit = |p: &str| LitStr::new(p, path.span());
+    if locales.is_empty() {
+        return locale_checked_paths(&path.value(), aliases, localized, locales)
+            .into_iter()
+            .map(|(p, check)| RegisteredPath { path: lit(&p), locale: quote! { __locale }, check })
+            .collect();
+    }
+
+    let mut registered: Vec<RegisteredPath> = std::iter::once(path)
+        .chain(aliases)
+        .map(|p| RegisteredPath { path: p.clone(), locale: quote! { None }, check: None })
+        .collect();
+    for locale in locales {
+        let localized_path = localized
+            .iter()
+            .find(|(l, _)| l.eq_ignore_ascii_case(locale))
+            .map_or_else(|| path.value(), |(_, path)| path.value());
+        let prefixed = format!("/{}{}", locale, localized_path.trim_end_matches('/'));
+        if !registered.iter().any(|r| r.path.value() == prefixed) {
+            registered.push(RegisteredPath { path: lit(&prefixed), locale: quote! { Some(#locale) }, check: None });
+        }
+    }
+    registered
+}
+
+/// The paths a variant parses from, each with the check on `__locale` it requires.
+///
+/// Prefixed URLs, or the nested enums of parent routes matched under a locale prefix, only
+/// accept the paths registered for that locale: its `path_<locale>`, or `path` when it has
+/// none. Unprefixed URLs match `path` and the `aliases`; on enums without `locales` they
+/// match every localized path as well.
+pub(crate) fn locale_checked_paths(
+    route_path: &str,
+    aliases: &[LitStr],
+    localized: &[(String, LitStr)],
+    locales: &[String],
+) -> Vec<(String, Option<TokenStream2>)> {
+    let localized: Vec<_> = localized
+        .iter()
+        .map(|(locale, path)| (locale, path.value()))
+        .filter(|(_, path)| path != route_path)
+        .collect();
+    let mut paths: Vec<(String, Option<TokenStream2>)> = vec![(route_path.to_string(), None)];
+    if !localized.is_empty() {
+        let overridden = localized.iter().map(|(locale, _)| locale);
+        paths[0].1 = Some(quote! {
+            match __locale {
+                Some(__locale) => ![#(#overridden),*].iter().any(|l| l.eq_ignore_ascii_case(__locale)),
+                None => true,
+            }
+        });
+    }
+    for alias in aliases {
+        push_checked(&mut paths, alias.value(), Some(quote! { __locale.is_none() }));
+    }
+    let mut localized_paths: Vec<&String> = Vec::new();
+    for (_, path) in &localized {
+        if !localized_paths.contains(&path) {
+            localized_paths.push(path);
+        }
+    }
+    for path in localized_paths {
+        let path_locales: Vec<_> = localized.iter().filter(|(_, p)| p == path).map(|(locale, _)| locale).collect();
+        let in_locale = quote! { [#(#path_locales),*].iter().any(|l| l.eq_ignore_ascii_case(__locale)) };
+        let check = match locales.is_empty() {
+            true => quote! { match __locale { Some(__locale) => #in_locale, None => true } },
+            false => quote! { __locale.is_some_and(|__locale| #in_locale) },
+        };
+        push_checked(&mut paths, path.clone(), Some(check));
+    }
+    paths
+}
+
+/// Adds `path`, or widens the check of an identical path already listed.
+fn push_checked(paths: &mut Vec<(String, Option<TokenStream2>)>, path: String, check: Option<TokenStream2>) {
+    match paths.iter_mut().find(|(p, _)| *p == path) {
+        Some((_, existing)) => {
+            *existing = match (existing.take(), check) {
+                (Some(a), Some(b)) => Some(quote! { (#a) || (#b) }),
+                _ => None,
+            };
+        }
+        None => paths.push((path, check)),
+    }
+}
+
+/// Match arms for `Routable::localized_path`, binding the requested locale as `__locale`.
+pub(crate) fn localized_path_arms(enum_ident: &Ident, data: &syn::DataEnum) -> syn::Result<Vec<TokenStream2>> {
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let nested = crate::field_attrs::nested_field(&variant.fields);
+        let Some(canonical) = crate::to_href_display::find_route_path(&variant.attrs).filter(|p| !p.is_empty()) else {
+            if matches!(&variant.fields, Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1) {
+                arms.push(quote! {
+                    #enum_ident::#variant_ident(nested) => ::leptos_routable::prelude::Routable::localized_path(nested, __locale),
+                });
+            }
+            continue;
+        };
+
+        let (variant_pat, canonical_build) = crate::to_href_display::href_builder_for_path(enum_ident, variant, &canonical)?;
+        let mut build = quote! { { #canonical_build } };
+        for (locale, path) in find_localized_paths(&variant.attrs).iter().rev() {
+            let (_, localized_build) = crate::to_href_display::href_builder_for_path(enum_ident, variant, &path.value())?;
+            build = quote! {
+                if __locale.eq_ignore_ascii_case(#locale) { #localized_build } else #build
+            };
+        }
+
+        if let Some(nested) = nested {
+            let binding = crate::field_attrs::nested_binding(nested);
+            arms.push(quote! {
+                #variant_pat => {
+                    let prefix_str = #build;
+                    ::leptos_routable::prelude::combine_paths(
+                        &prefix_str,
+                        &::leptos_routable::prelude::Routable::localized_path(#binding, __locale),
+                    )
+                }
+            });
+        } else {
+            arms.push(quote! { #variant_pat => #build, });
+        }
+    }
+    Ok(arms)
+}
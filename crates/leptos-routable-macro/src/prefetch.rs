@@ -0,0 +1,39 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::Ident;
+
+/// The `Routable::prefetch_in` arm of one variant: its `loader`, cached under `__url`, the
+/// full URL of the root route, its `lazy` chunk and the nested route it wraps. `None` for variants with nothing to prefetch.
+pub(crate) fn prefetch_arm(
+    enum_ident: &Ident,
+    variant: &syn::Variant,
+    loader: Option<&syn::Expr>,
+    lazy: bool,
+) -> Option<TokenStream2> {
+    let variant_ident = &variant.ident;
+    let load = loader.map(|loader| quote! {
+        ::leptos_routable::prelude::prefetch_route_data(__url, ::std::clone::Clone::clone(self), #loader);
+    });
+    let preload = lazy.then(|| crate::lazy::preload_chunk(enum_ident, variant_ident));
+
+    if let Some(nested) = crate::field_attrs::nested_field(&variant.fields) {
+        let binding = Ident::new("nested", proc_macro2::Span::call_site());
+        let pat = crate::field_attrs::nested_pattern(enum_ident, variant_ident, nested, &binding);
+        return Some(quote! {
+            #pat => {
+                #load
+                #preload
+                ::leptos_routable::prelude::Routable::prefetch_in(nested, __url);
+            }
+        });
+    }
+    if load.is_none() && preload.is_none() {
+        return None;
+    }
+    Some(quote! {
+        #enum_ident::#variant_ident { .. } => {
+            #load
+            #preload
+        }
+    })
+}
@@ -0,0 +1,45 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Attribute, Expr, Ident, LitStr};
+
+/// An enum-level `#[redirect(from = "/old", to = Variant)]`, registering `from` as a route
+/// that redirects to `to` on the client and, with a server integration, on the server.
+///
+/// `to` is a variant of the deriving enum or any expression implementing `Display`. Like guard
+/// redirects, it is resolved against the parent route a nested enum is mounted under.
+#[derive(std::fmt::Debug, FromMeta)]
+pub(crate) struct RedirectArgs {
+    from: LitStr,
+    to: Expr,
+}
+
+impl RedirectArgs {
+    /// Every `#[redirect(...)]` attribute, in declaration order.
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> darling::Result<Vec<Self>> {
+        attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("redirect"))
+            .map(|attr| Self::from_meta(&attr.meta))
+            .collect()
+    }
+
+    pub(crate) fn to_child_tokens(&self, enum_ident: &Ident) -> TokenStream2 {
+        let from = crate::localized::router_path(&self.from);
+        let to = match &self.to {
+            Expr::Path(path) if path.path.get_ident().is_some() => quote! { #enum_ident::#path },
+            to => quote! { #to },
+        };
+        quote! {
+            ::leptos_router::components::Route(
+                ::leptos_router::components::RouteProps::builder()
+                    .path(#from)
+                    .view(move || ::leptos_router::components::Redirect(
+                        ::leptos_router::components::RedirectProps::builder()
+                            .path(::leptos_routable::prelude::resolve_route_path(&(#to).to_string()))
+                            .build()
+                    ))
+                    .build())
+        }
+    }
+}
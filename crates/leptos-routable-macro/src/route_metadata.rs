@@ -0,0 +1,296 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use std::collections::HashMap;
+use darling::util::SpannedValue;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Fields, Ident, LitStr, Meta, Token, Variant};
+
+/// Metadata arguments shared by every route-like attribute, e.g.
+/// `#[route(path = "/asset/:id", title = "Asset {id}", meta(robots = "noindex"))]`.
+///
+/// `title`, `label` and `description` are format strings evaluated with the variant's fields in scope.
+#[derive(std::fmt::Debug, Default, darling::FromMeta)]
+pub(crate) struct RouteMetaArgs {
+    #[darling(default)]
+    pub(crate) title: Option<LitStr>,
+
+    /// Breadcrumb label, defaults to the variant name.
+    #[darling(default)]
+    pub(crate) label: Option<LitStr>,
+
+    #[darling(default)]
+    pub(crate) description: Option<LitStr>,
+
+    #[darling(default)]
+    pub(crate) meta: MetaTags,
+
+    #[darling(default)]
+    pub(crate) sitemap: Option<SitemapArgs>,
+
+    #[darling(default)]
+    pub(crate) robots: Option<RobotsArgs>,
+
+    /// `path_<locale>` arguments, which aren't metadata but are shared by every route attribute as well.
+    #[darling(flatten)]
+    pub(crate) localized: crate::localized::LocalizedPaths,
+}
+
+/// `meta(robots = "noindex", "og:title" = "About")`. Keys are identifiers or, for names that
+/// aren't, such as `og:title`, string literals.
+#[derive(std::fmt::Debug, Default)]
+pub(crate) struct MetaTags(HashMap<String, LitStr>);
+
+impl darling::FromMeta for MetaTags {
+    fn from_meta(item: &Meta) -> darling::Result<Self> {
+        let Meta::List(list) = item else {
+            return Err(darling::Error::unsupported_format("non-list").with_span(item));
+        };
+        let tags = list.parse_args_with(Punctuated::<MetaTag, Token![,]>::parse_terminated)?;
+        let mut errors = darling::Error::accumulator();
+        let mut map = HashMap::new();
+        for MetaTag { key, span, value } in tags {
+            if map.contains_key(&key) {
+                errors.push(darling::Error::duplicate_field(&key).with_span(&span));
+                continue;
+            }
+            map.insert(key, value);
+        }
+        errors.finish_with(Self(map))
+    }
+}
+
+/// One `key = "value"` pair of `meta(...)`.
+struct MetaTag {
+    key: String,
+    span: Span,
+    value: LitStr,
+}
+
+impl Parse for MetaTag {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (key, span) = if input.peek(LitStr) {
+            let key: LitStr = input.parse()?;
+            (key.value(), key.span())
+        } else {
+            let key = input.call(Ident::parse_any)?;
+            (key.to_string(), key.span())
+        };
+        input.parse::<Token![=]>()?;
+        Ok(Self { key, span, value: input.parse()? })
+    }
+}
+
+/// `sitemap(priority = 0.8, changefreq = "weekly", exclude)`
+#[derive(std::fmt::Debug, Default, darling::FromMeta)]
+pub(crate) struct SitemapArgs {
+    #[darling(default)]
+    priority: Option<SpannedValue<f32>>,
+
+    #[darling(default)]
+    changefreq: Option<LitStr>,
+
+    #[darling(default)]
+    exclude: SpannedValue<bool>,
+
+    /// Lists a guarded or protected route, which are left out by default.
+    #[darling(default)]
+    include: bool,
+}
+
+/// `robots(disallow)`
+#[derive(std::fmt::Debug, Default, darling::FromMeta)]
+pub(crate) struct RobotsArgs {
+    #[darling(default)]
+    disallow: bool,
+}
+
+const CHANGE_FREQUENCIES: [&str; 7] = ["always", "hourly", "daily", "weekly", "monthly", "yearly", "never"];
+
+/// `SitemapOptions` for the route table, defaults when no `sitemap(...)` is given.
+/// `guarded` routes are excluded unless they declare `sitemap(include)`.
+pub(crate) fn sitemap_options_tokens(args: Option<&RouteMetaArgs>, guarded: bool) -> syn::Result<TokenStream2> {
+    let sitemap = args.and_then(|a| a.sitemap.as_ref());
+    let priority = match sitemap.and_then(|s| s.priority.as_ref()) {
+        Some(priority) if !(0.0..=1.0).contains(&**priority) => {
+            return Err(syn::Error::new(priority.span(), "sitemap `priority` must be between 0.0 and 1.0"));
+        }
+        Some(priority) => {
+            let priority = **priority;
+            quote! { Some(#priority) }
+        }
+        None => quote! { None },
+    };
+    let changefreq = match sitemap.and_then(|s| s.changefreq.as_ref()) {
+        Some(lit) => {
+            let value = lit.value();
+            if !CHANGE_FREQUENCIES.contains(&value.as_str()) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("sitemap `changefreq` must be one of: {}", CHANGE_FREQUENCIES.join(", ")),
+                ));
+            }
+            let mut variant = value.clone();
+            variant[..1].make_ascii_uppercase();
+            let variant = Ident::new(&variant, lit.span());
+            quote! { Some(::leptos_routable::prelude::ChangeFreq::#variant) }
+        }
+        None => quote! { None },
+    };
+    if let Some(sitemap) = sitemap.filter(|s| *s.exclude && s.include) {
+        return Err(syn::Error::new(sitemap.exclude.span(), "sitemap `exclude` and `include` cannot be combined"));
+    }
+    let exclude = sitemap.is_some_and(|s| *s.exclude) || (guarded && !sitemap.is_some_and(|s| s.include));
+    Ok(quote! {
+        ::leptos_routable::prelude::SitemapOptions {
+            priority: #priority,
+            changefreq: #changefreq,
+            exclude: #exclude,
+        }
+    })
+}
+
+/// `RobotsOptions` for the route table.
+pub(crate) fn robots_options_tokens(args: Option<&RouteMetaArgs>) -> TokenStream2 {
+    let disallow = args.and_then(|a| a.robots.as_ref()).is_some_and(|r| r.disallow);
+    quote! {
+        ::leptos_routable::prelude::RobotsOptions { disallow: #disallow }
+    }
+}
+
+/// Match arms for `Routable::{title, description, meta, breadcrumbs}`.
+pub(crate) struct MetadataArms {
+    pub(crate) title: Vec<TokenStream2>,
+    pub(crate) description: Vec<TokenStream2>,
+    pub(crate) meta: Vec<TokenStream2>,
+    pub(crate) breadcrumbs: Vec<TokenStream2>,
+}
+
+impl MetadataArms {
+    pub(crate) fn new() -> Self {
+        Self { title: Vec::new(), description: Vec::new(), meta: Vec::new(), breadcrumbs: Vec::new() }
+    }
+
+    /// Pushes the arms for one variant. Nested variants (a single unnamed field or a `#[nested]`
+    /// one) prefer the metadata of the inner route and fall back to their own.
+    pub(crate) fn push(
+        &mut self,
+        enum_ident: &Ident,
+        variant: &Variant,
+        args: Option<&RouteMetaArgs>,
+    ) -> syn::Result<()> {
+        let variant_ident = &variant.ident;
+        let label = match args.and_then(|a| a.label.as_ref()) {
+            Some(lit) if lit.value().contains('{') => quote! { ::std::format!(#lit) },
+            Some(lit) => quote! { ::std::string::String::from(#lit) },
+            None => {
+                let name = variant_ident.to_string();
+                quote! { ::std::string::String::from(#name) }
+            }
+        };
+        let href_builder = match args {
+            Some(_) => crate::to_href_display::variant_href_builder(enum_ident, variant)?,
+            None => None,
+        };
+        let title = format_tokens(args.and_then(|a| a.title.as_ref()));
+        let description = format_tokens(args.and_then(|a| a.description.as_ref()));
+        let meta = meta_slice_tokens(args);
+
+        if let Some(nested) = crate::field_attrs::nested_field(&variant.fields) {
+            // Bind every field so templated parent titles can use the path params
+            let binding = crate::field_attrs::nested_binding(nested);
+            let pat = match &variant.fields {
+                Fields::Named(named) => {
+                    let field_idents = named.named.iter().filter_map(|f| f.ident.as_ref());
+                    quote! { #enum_ident::#variant_ident { #(#field_idents),* } }
+                }
+                _ => quote! { #enum_ident::#variant_ident(#binding, ..) },
+            };
+            self.title.push(quote! {
+                #[allow(unused_variables)]
+                #pat => Routable::title(#binding).or_else(|| #title),
+            });
+            self.description.push(quote! {
+                #[allow(unused_variables)]
+                #pat => Routable::description(#binding).or_else(|| #description),
+            });
+            self.meta.push(quote! {
+                #[allow(unused_variables)]
+                #pat => {
+                    let inner = Routable::meta(#binding);
+                    if inner.is_empty() { #meta } else { inner }
+                }
+            });
+            self.breadcrumbs.push(match href_builder {
+                Some((prefix_pat, build_code)) => quote! {
+                    #prefix_pat => ::leptos_routable::prelude::Breadcrumb::nest(
+                        #label,
+                        { #build_code },
+                        Routable::breadcrumbs(#binding),
+                    ),
+                },
+                None => quote! {
+                    #pat => ::leptos_routable::prelude::Breadcrumb::lift(Routable::breadcrumbs(#binding)),
+                },
+            });
+            return Ok(());
+        }
+
+        match &variant.fields {
+            Fields::Unnamed(_) => {}
+            Fields::Named(named) => {
+                let field_idents = named.named.iter().filter_map(|f| f.ident.as_ref());
+                let pat = quote! { #enum_ident::#variant_ident { #(#field_idents),* } };
+                self.title.push(quote! {
+                    #[allow(unused_variables)]
+                    #pat => #title,
+                });
+                self.description.push(quote! {
+                    #[allow(unused_variables)]
+                    #pat => #description,
+                });
+                self.meta.push(quote! { #enum_ident::#variant_ident { .. } => #meta, });
+                self.breadcrumbs.push(leaf_breadcrumb(&pat, &label, href_builder.is_some()));
+            }
+            Fields::Unit => {
+                let pat = quote! { #enum_ident::#variant_ident };
+                self.title.push(quote! { #pat => #title, });
+                self.description.push(quote! { #pat => #description, });
+                self.meta.push(quote! { #pat => #meta, });
+                self.breadcrumbs.push(leaf_breadcrumb(&pat, &label, href_builder.is_some()));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn leaf_breadcrumb(pat: &TokenStream2, label: &TokenStream2, has_path: bool) -> TokenStream2 {
+    if has_path {
+        quote! {
+            #[allow(unused_variables)]
+            #pat => ::std::vec![::leptos_routable::prelude::Breadcrumb::new(#label, self.to_string())],
+        }
+    } else {
+        quote! {
+            #[allow(unused_variables)]
+            #pat => ::std::vec::Vec::new(),
+        }
+    }
+}
+
+/// `Some(format!(..))` for templated strings, `Some(String::from(..))` for plain ones.
+fn format_tokens(lit: Option<&LitStr>) -> TokenStream2 {
+    match lit {
+        Some(lit) if lit.value().contains('{') => quote! { Some(::std::format!(#lit)) },
+        Some(lit) => quote! { Some(::std::string::String::from(#lit)) },
+        None => quote! { None },
+    }
+}
+
+fn meta_slice_tokens(args: Option<&RouteMetaArgs>) -> TokenStream2 {
+    let mut entries: Vec<_> = args.map(|a| a.meta.0.iter().collect()).unwrap_or_default();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    let entries = entries.into_iter().map(|(key, value)| quote! { (#key, #value) });
+    quote! { &[#(#entries),*] }
+}
@@ -0,0 +1,145 @@
+use crate::field_attrs::{nested_field, FieldAttrs};
+use crate::localized::find_localized_paths;
+use crate::route_metadata::{robots_options_tokens, sitemap_options_tokens, RouteMetaArgs};
+use crate::to_href_display::{find_route_aliases, find_route_path, parse_segments, RouteSegment};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{Fields, Ident, Type, Variant};
+
+/// `ROUTE_TABLE` entries and `all_static_routes`/`prerender_routes` statements for `#[derive(Routable)]`.
+pub(crate) struct RouteTable {
+    pub(crate) entries: Vec<TokenStream2>,
+    pub(crate) static_routes: Vec<TokenStream2>,
+    pub(crate) prerender_routes: Vec<TokenStream2>,
+}
+
+impl RouteTable {
+    pub(crate) fn new() -> Self {
+        Self { entries: Vec::new(), static_routes: Vec::new(), prerender_routes: Vec::new() }
+    }
+
+    /// Records one variant. `kind` is the `RouteKind` of its route attribute, `None` for
+    /// variants without one; those only show up when they flatten a nested enum.
+    /// `guarded` variants render through a guard or are protected.
+    pub(crate) fn push(
+        &mut self,
+        enum_ident: &Ident,
+        variant: &Variant,
+        kind: Option<TokenStream2>,
+        guarded: bool,
+        metadata: Option<&RouteMetaArgs>,
+        static_params: Option<&syn::Expr>,
+    ) -> syn::Result<()> {
+        let variant_ident = &variant.ident;
+        let variant_name = variant_ident.to_string();
+        let is_fallback = variant.attrs.iter().any(|attr| attr.path().is_ident("fallback"));
+        let nested_ty = nested_field(&variant.fields).map(|field| &field.ty);
+
+        let kind = match (kind, nested_ty) {
+            (Some(kind), _) => kind,
+            (None, Some(_)) => quote! { ::leptos_routable::prelude::RouteKind::Parent },
+            (None, None) => return Ok(()),
+        };
+        let path = find_route_path(&variant.attrs).unwrap_or_default();
+        let aliases = find_route_aliases(&variant.attrs);
+        let localized = find_localized_paths(&variant.attrs).into_iter().map(|(locale, path)| quote! { (#locale, #path) });
+        let sitemap = sitemap_options_tokens(metadata, guarded)?;
+        let robots = robots_options_tokens(metadata);
+
+        let mut params = Vec::new();
+        let mut query = Vec::new();
+        if let Fields::Named(named) = &variant.fields {
+            let segments = parse_segments(&path);
+            for segment in &segments {
+                let (RouteSegment::Param(name) | RouteSegment::OptionalParam(name) | RouteSegment::Wildcard(name)) = segment else {
+                    continue;
+                };
+                let ty = named
+                    .named
+                    .iter()
+                    .find(|f| f.ident.as_ref().is_some_and(|i| i == name))
+                    .map(|f| type_name(&f.ty))
+                    .unwrap_or_default();
+                params.push(quote! { ::leptos_routable::prelude::ParamInfo { name: #name, ty: #ty } });
+            }
+            for field in &named.named {
+                let name = field.ident.as_ref().map(ToString::to_string).unwrap_or_default();
+                let attrs = FieldAttrs::from_field(field)?;
+                let in_path = segments.iter().any(|segment| matches!(
+                    segment,
+                    RouteSegment::Param(n) | RouteSegment::OptionalParam(n) | RouteSegment::Wildcard(n) if *n == name
+                ));
+                if in_path || attrs.is_fragment() || attrs.is_flatten() || attrs.is_nested() {
+                    continue;
+                }
+                query.push(attrs.query_key(&name));
+            }
+        }
+
+        let nested = match nested_ty {
+            Some(ty) => quote! { <#ty as ::leptos_routable::prelude::Routable>::ROUTE_TABLE },
+            None => quote! { &[] },
+        };
+
+        self.entries.push(quote! {
+            ::leptos_routable::prelude::RouteInfo {
+                variant: #variant_name,
+                path: #path,
+                aliases: &[#(#aliases),*],
+                localized: &[#(#localized),*],
+                kind: #kind,
+                params: &[#(#params),*],
+                query: &[#(#query),*],
+                fallback: #is_fallback,
+                sitemap: #sitemap,
+                robots: #robots,
+                nested: #nested,
+            }
+        });
+
+        match (&variant.fields, nested_ty) {
+            (Fields::Unit, _) if !is_fallback => {
+                self.static_routes.push(quote! { __routes.push(#enum_ident::#variant_ident); });
+                self.prerender_routes.push(quote! { __routes.push(#enum_ident::#variant_ident); });
+            }
+            // Parents with path params have no static routes without their params
+            (Fields::Unnamed(_), Some(ty)) => {
+                self.static_routes.push(quote! {
+                    __routes.extend(
+                        <#ty as ::leptos_routable::prelude::Routable>::all_static_routes()
+                            .map(#enum_ident::#variant_ident)
+                    );
+                });
+                self.prerender_routes.push(quote! {
+                    __routes.extend(
+                        <#ty as ::leptos_routable::prelude::Routable>::prerender_routes()
+                            .into_iter()
+                            .map(#enum_ident::#variant_ident)
+                    );
+                });
+            }
+            _ => {}
+        }
+
+        if let Some(static_params) = static_params {
+            let variant_pat = match &variant.fields {
+                Fields::Named(_) => quote! { #enum_ident::#variant_ident { .. } },
+                Fields::Unnamed(_) => quote! { #enum_ident::#variant_ident(..) },
+                Fields::Unit => quote! { #enum_ident::#variant_ident },
+            };
+            self.prerender_routes.push(quote! {
+                __routes.extend(
+                    #static_params()
+                        .into_iter()
+                        .filter(|route: &#enum_ident| matches!(route, #variant_pat))
+                );
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The type as written, without the spaces `ToTokens` puts around `<` and `>`.
+fn type_name(ty: &Type) -> String {
+    ty.to_token_stream().to_string().replace(' ', "")
+}
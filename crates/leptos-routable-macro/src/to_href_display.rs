@@ -3,11 +3,13 @@ use syn::{
     spanned::Spanned, Attribute, Error, Fields, Ident, LitStr,
     Type, Variant,
 };
+use crate::field_attrs::{nested_binding, nested_field, FieldAttrs};
 
 struct FieldMeta {
     name: String,
     ty: Type,
     span: proc_macro2::Span,
+    attrs: FieldAttrs,
 }
 
 fn extract_variant_fields(
@@ -25,6 +27,7 @@ fn extract_variant_fields(
                     name: ident.to_string(),
                     ty: f.ty.clone(),
                     span: f.span(),
+                    attrs: FieldAttrs::from_field(f)?,
                 });
             }
             Ok(out)
@@ -48,6 +51,7 @@ fn extract_variant_fields(
                 name: "_0".to_string(),
                 ty: only_field.ty.clone(),
                 span: only_field.span(),
+                attrs: FieldAttrs::default(),
             }])
         }
     }
@@ -89,6 +93,24 @@ fn validate_path_and_fields(
                     ));
                 }
             }
+            RouteSegment::Wildcard(name) => {
+                used_fields.push(name.clone());
+                if !fields.iter().any(|f| f.name == *name) {
+                    return Err(Error::new(
+                        variant_ident.span(),
+                        format!("Wildcard `*{}` not found in `{}`.", name, variant_ident),
+                    ));
+                }
+            }
+        }
+    }
+
+    if let Some(pos) = segments.iter().position(|s| matches!(s, RouteSegment::Wildcard(_))) {
+        if pos + 1 != segments.len() {
+            return Err(Error::new(
+                variant_ident.span(),
+                format!("Wildcard segment must be the last segment of the path in `{}`.", variant_ident),
+            ));
         }
     }
 
@@ -99,12 +121,71 @@ fn validate_path_and_fields(
         }
     }
 
-    // Otherwise leftover fields must be Option<T>
+    // At most one `#[fragment]`, and it must be optional since URLs rarely carry one
+    let fragments: Vec<_> = fields.iter().filter(|f| f.attrs.is_fragment()).collect();
+    if fragments.len() > 1 {
+        return Err(Error::new(
+            fragments[1].span,
+            format!("Only one `#[fragment]` field is allowed in `{}`.", variant_ident),
+        ));
+    }
+    if let Some(f) = fragments.first() {
+        if !is_option_type(&f.ty) {
+            return Err(Error::new(
+                f.span,
+                format!("`#[fragment]` field `{}` must be `Option<T>`.", f.name),
+            ));
+        }
+    }
+
+    // Parent routes carry path params next to their `#[nested]` route, the query belongs to the nested route
+    let nested: Vec<_> = fields.iter().filter(|f| f.attrs.is_nested()).collect();
+    if nested.len() > 1 {
+        return Err(Error::new(
+            nested[1].span,
+            format!("Only one `#[nested]` field is allowed in `{}`.", variant_ident),
+        ));
+    }
+    if !nested.is_empty() {
+        for f in fields {
+            if f.attrs.is_nested() {
+                if used_fields.contains(&f.name) {
+                    return Err(Error::new(
+                        f.span,
+                        format!("`#[nested]` field `{}` cannot also be a path param.", f.name),
+                    ));
+                }
+            } else if !used_fields.contains(&f.name) {
+                return Err(Error::new(
+                    f.span,
+                    format!(
+                        "Field `{}` must be a path param: parent routes only carry path params besides their `#[nested]` route.",
+                        f.name
+                    ),
+                ));
+            }
+        }
+        return Ok(());
+    }
+
+    // Otherwise leftover fields must be Option<T> or explicitly marked `#[query]`
     for f in fields {
-        if !used_fields.contains(&f.name) && !is_option_type(&f.ty) {
+        if used_fields.contains(&f.name) {
+            if f.attrs.is_query() || f.attrs.is_fragment() {
+                return Err(Error::new(
+                    f.span,
+                    format!("Field `{}` is bound in the path and cannot also be `#[query]` or `#[fragment]`.", f.name),
+                ));
+            }
+            continue;
+        }
+        if !f.attrs.is_query() && !is_option_type(&f.ty) && !is_collection_type(&f.ty) {
             return Err(Error::new(
                 f.span,
-                format!("Field `{}` not used in path, so must be `Option<T>` to appear as a query.", f.name),
+                format!(
+                    "Field `{}` not used in path, so must be `Option<T>`, a collection, or marked `#[query]` to appear as a query.",
+                    f.name
+                ),
             ));
         }
     }
@@ -116,33 +197,25 @@ fn build_variant_pattern(
     enum_ident: &Ident,
     variant_ident: &Ident,
     fields: &Fields,
-) -> syn::Result<(proc_macro2::TokenStream, Vec<(String, Type)>)> {
+) -> proc_macro2::TokenStream {
     match fields {
-        Fields::Unit => {
-            let pat = quote!( #enum_ident::#variant_ident );
-            Ok((pat, vec![]))
-        }
+        Fields::Unit => quote!( #enum_ident::#variant_ident ),
         Fields::Named(named) => {
-            let mut field_names = Vec::new();
-            let mut field_info = Vec::new();
-            for f in &named.named {
-                let nm = f.ident.as_ref().unwrap().clone();
-                field_names.push(quote!(#nm));
-                field_info.push((nm.to_string(), f.ty.clone()));
-            }
-            let pat = quote!( #enum_ident::#variant_ident { #( #field_names ),* } );
-            Ok((pat, field_info))
+            let field_names = named.named.iter().map(|f| {
+                let nm = f.ident.as_ref().unwrap();
+                quote!(#nm)
+            });
+            quote!( #enum_ident::#variant_ident { #( #field_names ),* } )
         }
         Fields::Unnamed(unnamed) => {
             let f = &unnamed.unnamed[0];
             let field_ident = syn::Ident::new("_0", f.span());
-            let pat = quote!( #enum_ident::#variant_ident(#field_ident) );
-            Ok((pat, vec![("_0".to_string(), f.ty.clone())]))
+            quote!( #enum_ident::#variant_ident(#field_ident) )
         }
     }
 }
 
-fn generate_path_builder(route: &str, fields: &[(String, Type)]) -> proc_macro2::TokenStream {
+fn generate_path_builder(route: &str, fields: &[FieldMeta]) -> proc_macro2::TokenStream {
     let segments = parse_segments(route);
     let mut used_fields = Vec::new();
 
@@ -150,28 +223,53 @@ fn generate_path_builder(route: &str, fields: &[(String, Type)]) -> proc_macro2:
         .into_iter()
         .map(|seg| match seg {
             RouteSegment::Static(txt) => quote! {
-                if path.is_empty() {
-                    path.push('/');
-                } else if !path.ends_with('/') {
-                    path.push('/');
+                if __path.is_empty() {
+                    __path.push('/');
+                } else if !__path.ends_with('/') {
+                    __path.push('/');
                 }
-                path.push_str(#txt);
+                __path.push_str(#txt);
             },
             RouteSegment::Param(name) => {
                 used_fields.push(name.clone());
                 let field_ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
                 quote! {
-                    path.push('/');
-                    path.push_str(&#field_ident.to_string());
+                    __path.push('/');
+                    __path.push_str(&::leptos_routable::prelude::encode_path_segment(&#field_ident.to_string()));
                 }
             }
             RouteSegment::OptionalParam(name) => {
                 used_fields.push(name.clone());
                 let field_ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+                // An empty value leaves the segment out, as it reads back as `None`
                 quote! {
-                    if let Some(ref val) = #field_ident {
-                        path.push('/');
-                        path.push_str(&val.to_string());
+                    if let Some(val) = #field_ident.as_ref().map(|val| val.to_string()).filter(|val| !val.is_empty()) {
+                        __path.push('/');
+                        __path.push_str(&::leptos_routable::prelude::encode_path_segment(&val));
+                    }
+                }
+            }
+            RouteSegment::Wildcard(name) => {
+                used_fields.push(name.clone());
+                let field_ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+                let is_vec = fields.iter().any(|f| f.name == name && is_vec_type(&f.ty));
+                // A `Vec` wildcard encodes each element as its own segment, anything else
+                // is rendered once and split on `/` so the slashes stay literal. An empty
+                // value renders no segment at all.
+                let parts = if is_vec {
+                    quote! { #field_ident.iter().map(|part| part.to_string()).collect::<Vec<_>>() }
+                } else {
+                    quote! {
+                        match #field_ident.to_string() {
+                            value if value.is_empty() => Vec::new(),
+                            value => value.split('/').map(str::to_owned).collect::<Vec<_>>(),
+                        }
+                    }
+                };
+                quote! {
+                    for part in #parts {
+                        __path.push('/');
+                        __path.push_str(&::leptos_routable::prelude::encode_path_segment(&part));
                     }
                 }
             }
@@ -180,45 +278,119 @@ fn generate_path_builder(route: &str, fields: &[(String, Type)]) -> proc_macro2:
 
     let leftover_fields: Vec<_> = fields
         .iter()
-        .filter(|(n, _)| !used_fields.contains(n))
+        .filter(|f| !used_fields.contains(&f.name) && !f.attrs.is_fragment() && !f.attrs.is_nested())
         .collect();
 
-    let query_push = leftover_fields.into_iter().map(|(fname, fty)| {
-        if is_option_type(fty) {
-            let field_ident = syn::Ident::new(fname, proc_macro2::Span::call_site());
-            quote! {
-                if let Some(ref val) = #field_ident {
-                    query_vec.push((#fname.to_owned(), val.to_string()));
-                }
+    let query_push = leftover_fields.into_iter().map(|f| {
+        let field_ident = syn::Ident::new(&f.name, proc_macro2::Span::call_site());
+        query_push_tokens(&quote!(#field_ident), &f.ty, &f.attrs, &f.name, f.attrs.is_query())
+    });
+
+    let fragment_push = fields.iter().find(|f| f.attrs.is_fragment()).map(|f| {
+        let field_ident = syn::Ident::new(&f.name, proc_macro2::Span::call_site());
+        quote! {
+            if let Some(ref val) = #field_ident {
+                __path.push('#');
+                __path.push_str(&::leptos_routable::prelude::encode_fragment(&val.to_string()));
             }
-        } else {
-            quote!()
         }
     });
 
     quote! {
-        let mut path = String::new();
+        let mut __path = String::new();
         #(#segment_stmts)*
 
-        let mut query_vec: Vec<(String, String)> = Vec::new();
+        if __path.is_empty() {
+            __path.push('/');
+        }
+
+        // Values are pushed already encoded so separators can stay literal
+        let mut __query_vec: Vec<(String, String)> = Vec::new();
         #(#query_push)*
 
-        if !query_vec.is_empty() {
-            query_vec.sort_by(|a, b| a.0.cmp(&b.0));
-            path.push('?');
-            let mut first = true;
-            for (k, v) in query_vec {
-                if !first { path.push('&'); } else { first = false; }
-                path.push_str(&k);
-                path.push('=');
-                path.push_str(&v);
+        if !__query_vec.is_empty() {
+            __query_vec.sort_by(|a, b| a.0.cmp(&b.0));
+            __path.push('?');
+            let mut __first = true;
+            for (k, v) in __query_vec {
+                if !__first { __path.push('&'); } else { __first = false; }
+                __path.push_str(&::leptos_routable::prelude::encode_query_component(&k));
+                __path.push('=');
+                __path.push_str(&v);
             }
         }
 
-        if path.is_empty() {
-            path.push('/');
+        #fragment_push
+        __path
+    }
+}
+
+/// Tokens pushing one field's `(key, encoded value)` pairs onto `__query_vec`.
+/// `value` is an expression for the field (a binding or `self.field`).
+pub(crate) fn query_push_tokens(
+    value: &proc_macro2::TokenStream,
+    ty: &Type,
+    attrs: &FieldAttrs,
+    field_name: &str,
+    required: bool,
+) -> proc_macro2::TokenStream {
+    let key = attrs.query_key(field_name);
+    if attrs.is_flatten() {
+        quote! {
+            <#ty as ::leptos_routable::prelude::RouteQuery>::append_query(&#value, &mut __query_vec);
+        }
+    } else if is_collection_type(ty) {
+        // `HashSet` iteration order is random, so its values are sorted to keep URLs stable
+        let sort_parts = is_hash_set_type(ty).then(|| quote! { parts.sort(); });
+        // Repeated keys by default, a single joined value with `separator`
+        match attrs.query_separator() {
+            Some(sep) => {
+                let sep = sep.to_string();
+                quote! {
+                    {
+                        let mut parts: Vec<String> = #value
+                            .iter()
+                            .map(|val| ::leptos_routable::prelude::encode_query_component(&val.to_string()))
+                            .collect();
+                        #sort_parts
+                        if !parts.is_empty() {
+                            __query_vec.push((#key.to_owned(), parts.join(#sep)));
+                        }
+                    }
+                }
+            }
+            None => quote! {
+                {
+                    let mut parts: Vec<String> = #value
+                        .iter()
+                        .map(|val| ::leptos_routable::prelude::encode_query_component(&val.to_string()))
+                        .collect();
+                    #sort_parts
+                    for val in parts {
+                        __query_vec.push((#key.to_owned(), val));
+                    }
+                }
+            },
+        }
+    } else if is_option_type(ty) {
+        quote! {
+            if let Some(ref val) = #value {
+                __query_vec.push((
+                    #key.to_owned(),
+                    ::leptos_routable::prelude::encode_query_component(&val.to_string()),
+                ));
+            }
+        }
+    } else if required {
+        // Required query params are always emitted
+        quote! {
+            __query_vec.push((
+                #key.to_owned(),
+                ::leptos_routable::prelude::encode_query_component(&#value.to_string()),
+            ));
         }
-        path
+    } else {
+        quote!()
     }
 }
 
@@ -230,13 +402,16 @@ pub(crate) enum RouteSegment {
     Static(String),
     Param(String),
     OptionalParam(String),
+    Wildcard(String),
 }
 
 pub(crate) fn parse_segments(route: &str) -> Vec<RouteSegment> {
     let without_leading = route.trim_start_matches('/');
     let mut segs = Vec::new();
     for part in without_leading.split('/') {
-        if part.starts_with(':') {
+        if let Some(name) = part.strip_prefix('*') {
+            segs.push(RouteSegment::Wildcard(name.to_string()));
+        } else if part.starts_with(':') {
             if let Some(stripped) = part.strip_suffix('?') {
                 segs.push(RouteSegment::OptionalParam(
                     stripped.trim_start_matches(':').to_string(),
@@ -260,6 +435,46 @@ pub(crate) fn is_option_type(ty: &Type) -> bool {
     false
 }
 
+/// The `T` of `Option<T>`, `Vec<T>` and the set types, or `ty` itself for anything else.
+pub(crate) fn inner_type(ty: &Type) -> &Type {
+    if let syn::Type::Path(tp) = ty {
+        if let Some(syn::PathArguments::AngleBracketed(args)) = tp.path.segments.last().map(|seg| &seg.arguments) {
+            if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                return inner;
+            }
+        }
+    }
+    ty
+}
+
+pub(crate) fn is_vec_type(ty: &Type) -> bool {
+    if let syn::Type::Path(tp) = ty {
+        if let Some(seg) = tp.path.segments.last() {
+            return seg.ident == "Vec";
+        }
+    }
+    false
+}
+
+/// `Vec<T>`, `HashSet<T>` and `BTreeSet<T>` fields map to multi-valued query params.
+pub(crate) fn is_collection_type(ty: &Type) -> bool {
+    if let syn::Type::Path(tp) = ty {
+        if let Some(seg) = tp.path.segments.last() {
+            return seg.ident == "Vec" || seg.ident == "HashSet" || seg.ident == "BTreeSet";
+        }
+    }
+    false
+}
+
+fn is_hash_set_type(ty: &Type) -> bool {
+    if let syn::Type::Path(tp) = ty {
+        if let Some(seg) = tp.path.segments.last() {
+            return seg.ident == "HashSet";
+        }
+    }
+    false
+}
+
 pub(crate) fn find_route_path(attrs: &[Attribute]) -> Option<String> {
     for attr in attrs {
         // TODO: Integrate into Routable
@@ -273,6 +488,8 @@ pub(crate) fn find_route_path(attrs: &[Attribute]) -> Option<String> {
                     let value = meta.value()?;
                     let str = value.parse::<LitStr>()?;
                     path = Some(str.value());
+                } else {
+                    skip_meta_value(&meta)?;
                 }
                 Ok(())
             });
@@ -282,6 +499,98 @@ pub(crate) fn find_route_path(attrs: &[Attribute]) -> Option<String> {
     None
 }
 
+/// The `aliases = ["/old/:id", ...]` of a route-like attribute, empty without any.
+pub(crate) fn find_route_aliases(attrs: &[Attribute]) -> Vec<LitStr> {
+    let mut aliases = Vec::new();
+    for attr in attrs {
+        if attr.path().is_ident("route")
+            || attr.path().is_ident("parent_route")
+            || attr.path().is_ident("protected_route")
+            || attr.path().is_ident("protected_parent_route")  {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("aliases") {
+                    let array = meta.value()?.parse::<syn::ExprArray>()?;
+                    for elem in array.elems {
+                        if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) = elem {
+                            aliases.push(lit);
+                        }
+                    }
+                } else {
+                    skip_meta_value(&meta)?;
+                }
+                Ok(())
+            });
+        }
+    }
+    aliases
+}
+
+/// Checks that every alias (or localized path) binds exactly the parameters of the canonical `path`.
+pub(crate) fn validate_aliases(path: &str, aliases: &[LitStr]) -> syn::Result<()> {
+    let param_names = |route: &str| {
+        let mut names: Vec<String> = parse_segments(route)
+            .into_iter()
+            .filter_map(|segment| match segment {
+                RouteSegment::Static(_) => None,
+                RouteSegment::Param(name) | RouteSegment::OptionalParam(name) | RouteSegment::Wildcard(name) => Some(name),
+            })
+            .collect();
+        names.sort();
+        names
+    };
+    let expected = param_names(path);
+    for alias in aliases {
+        if param_names(&alias.value()) != expected {
+            return Err(syn::Error::new(
+                alias.span(),
+                format!("`{}` must use the same parameters as `{}`", alias.value(), path),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Consumes the value of a nested meta item we don't care about (`key = expr` or `key(...)`),
+/// so parsing can continue with the following items.
+pub(crate) fn skip_meta_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        content.parse::<proc_macro2::TokenStream>()?;
+    }
+    Ok(())
+}
+
+/// The match pattern and path-building block of a variant with a route path, or `None` for
+/// variants without one (flattened nested enums). Nested variants bind their inner route as `_0`,
+/// or by the name of their `#[nested]` field, and only build their own prefix.
+pub(crate) fn variant_href_builder(
+    enum_ident: &Ident,
+    variant: &Variant,
+) -> syn::Result<Option<(proc_macro2::TokenStream, proc_macro2::TokenStream)>> {
+    match find_route_path(&variant.attrs) {
+        Some(route_path) if !route_path.is_empty() => href_builder_for_path(enum_ident, variant, &route_path).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Like [`variant_href_builder`], building the href from `route_path` instead of the
+/// variant's `path`, e.g. one of its localized paths.
+pub(crate) fn href_builder_for_path(
+    enum_ident: &Ident,
+    variant: &Variant,
+    route_path: &str,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    let Variant { ident, fields, .. } = variant;
+    let field_infos = extract_variant_fields(enum_ident, ident, fields)?;
+    validate_path_and_fields(route_path, &field_infos, fields, ident)?;
+    let variant_pat = build_variant_pattern(enum_ident, ident, fields);
+    let build_code = generate_path_builder(route_path, &field_infos);
+    Ok((variant_pat, build_code))
+}
+
 pub(crate) fn generate_to_href_display_impl(
     enum_ident: &syn::Ident,
     data: &syn::DataEnum,
@@ -289,37 +598,28 @@ pub(crate) fn generate_to_href_display_impl(
     let mut match_arms = Vec::new();
 
     for variant in &data.variants {
-        let Variant { ident, fields, attrs, .. } = variant;
-        let route_path = match find_route_path(attrs) {
-            Some(p) if !p.is_empty() => p,
-            _ => {
-                if let Fields::Unnamed(unnamed) = fields {
-                    if unnamed.unnamed.len() == 1 {
-                        let pat = quote!( #enum_ident::#ident(nested) );
-                        match_arms.push(quote! { #pat => nested.to_string() });
-                    }
+        let Variant { ident, fields, .. } = variant;
+        let Some((variant_pat, build_code)) = variant_href_builder(enum_ident, variant)? else {
+            if let Fields::Unnamed(unnamed) = fields {
+                if unnamed.unnamed.len() == 1 {
+                    let pat = quote!( #enum_ident::#ident(nested) );
+                    match_arms.push(quote! { #pat => nested.to_string() });
                 }
-                continue;
             }
+            continue;
         };
 
-        let field_infos = extract_variant_fields(enum_ident, ident, fields)?;
-        validate_path_and_fields(&route_path, &field_infos, fields, ident)?;
-        let (variant_pat, fields_for_build) = build_variant_pattern(enum_ident, ident, fields)?;
-        let build_code = generate_path_builder(&route_path, &fields_for_build);
-
-        // If exactly one unnamed field, prefix + nested
-        if let Fields::Unnamed(unnamed) = fields {
-            if unnamed.unnamed.len() == 1 {
-                match_arms.push(quote! {
-                    #variant_pat => {
-                        let prefix_str = { #build_code };
-                        let nested_str = _0.to_string();
-                        ::leptos_routable::prelude::combine_paths(&prefix_str, &nested_str)
-                    }
-                });
-                continue;
-            }
+        // Parent routes: prefix + nested
+        if let Some(nested) = nested_field(fields) {
+            let binding = nested_binding(nested);
+            match_arms.push(quote! {
+                #variant_pat => {
+                    let prefix_str = { #build_code };
+                    let nested_str = #binding.to_string();
+                    ::leptos_routable::prelude::combine_paths(&prefix_str, &nested_str)
+                }
+            });
+            continue;
         }
 
         match_arms.push(quote! {
@@ -0,0 +1,92 @@
+use crate::combine_paths::combine_paths;
+use crate::current_route::use_current_route;
+use crate::Routable;
+use leptos::prelude::*;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// One level of a route's hierarchy, as returned by [`Routable::breadcrumbs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Breadcrumb<R> {
+    /// The `label` attribute of the level, or its variant name.
+    pub label: String,
+    /// The href of this level.
+    pub href: String,
+    /// The route `href` parses to, if any. Parent levels resolve to the nested
+    /// enum's index route when it has one.
+    pub route: Option<R>,
+}
+
+impl<R: FromStr> Breadcrumb<R> {
+    pub fn new(label: impl Into<String>, href: impl Into<String>) -> Self {
+        let href = href.into();
+        let route = R::from_str(&href).ok();
+        Self { label: label.into(), href, route }
+    }
+
+    /// Crumbs of a nested variant: the parent level followed by the nested enum's crumbs
+    /// below `prefix`. A nested index route (same href as the parent) is folded into the parent.
+    #[doc(hidden)]
+    pub fn nest<N>(label: String, prefix: String, inner: Vec<Breadcrumb<N>>) -> Vec<Self> {
+        let mut crumbs = vec![Self::new(label, prefix.clone())];
+        for crumb in inner {
+            let href = combine_paths(&prefix, &crumb.href);
+            if crumbs.last().is_some_and(|last| last.href == href) {
+                continue;
+            }
+            crumbs.push(Self::new(crumb.label, href));
+        }
+        crumbs
+    }
+
+    /// Crumbs of a nested enum mounted without a path of its own.
+    #[doc(hidden)]
+    pub fn lift<N>(inner: Vec<Breadcrumb<N>>) -> Vec<Self> {
+        inner
+            .into_iter()
+            .map(|crumb| Self::new(crumb.label, crumb.href))
+            .collect()
+    }
+}
+
+/// Renders the breadcrumbs of the current route as a `<nav>` with an ordered list of links.
+/// The last crumb is marked with `aria-current="page"`.
+///
+/// The route enum is given as a generic: `<Breadcrumbs<AppRoutes> class="crumbs"/>`.
+#[component]
+pub fn Breadcrumbs<R>(
+    /// Classes applied to the `<ol>`.
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// Carries the route enum; set it through `<Breadcrumbs<AppRoutes>/>` instead.
+    #[prop(optional)]
+    _route: PhantomData<R>,
+) -> impl IntoView
+where
+    R: Routable + FromStr + Clone + PartialEq + Send + Sync + 'static,
+{
+    let current = use_current_route::<R>();
+    let crumbs = move || {
+        let crumbs = current.with(|route| route.as_ref().map(Routable::breadcrumbs).unwrap_or_default());
+        let last = crumbs.len().saturating_sub(1);
+        crumbs
+            .into_iter()
+            .enumerate()
+            .map(|(i, crumb)| {
+                view! {
+                    <li>
+                        <a href=crumb.href aria-current=(i == last).then_some("page")>
+                            {crumb.label}
+                        </a>
+                    </li>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <nav aria-label="Breadcrumb">
+            <ol class=class>{crumbs}</ol>
+        </nav>
+    }
+}
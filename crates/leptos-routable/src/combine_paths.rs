@@ -2,6 +2,12 @@ pub fn combine_paths(prefix: &str, nested: &str) -> String {
     let prefix = prefix.trim_end_matches('/');
     let nested = nested.trim_start_matches('/');
 
+    // A nested index route only adds its query or fragment, `/admin?q=x` like `/admin`
+    if nested.starts_with(['?', '#']) {
+        let prefix = if prefix.is_empty() { "/" } else { prefix };
+        return format!("{}{}", prefix, nested);
+    }
+
     // If both are empty or "/", just return "/"
     let prefix_is_root = prefix.is_empty() || prefix == "/";
     let nested_is_root = nested.is_empty() || nested == "/";
@@ -0,0 +1,47 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+use std::str::FromStr;
+
+/// Reactively rebuilds the current URL (path, query and fragment) from [`use_location`].
+pub(crate) fn use_current_url() -> Memo<String> {
+    let location = use_location();
+    Memo::new(move |_| {
+        let mut url = location.pathname.get();
+        let search = location.search.get();
+        let search = search.trim_start_matches('?');
+        if !search.is_empty() {
+            url.push('?');
+            url.push_str(search);
+        }
+        let hash = location.hash.get();
+        let hash = hash.trim_start_matches('#');
+        if !hash.is_empty() {
+            url.push('#');
+            url.push_str(hash);
+        }
+        url
+    })
+}
+
+/// Returns the current location parsed as the route enum `R`, or [`None`] if no
+/// variant matches. Re-parses whenever the location changes.
+///
+/// `R` should be the root enum passed to the router; nested enums are produced
+/// through their parent variant, e.g. `AppRoutes::Dashboard(DashboardRoutes::Settings)`.
+pub fn use_current_route<R>() -> Memo<Option<R>>
+where
+    R: FromStr + Clone + PartialEq + Send + Sync + 'static,
+{
+    let url = use_current_url();
+    Memo::new(move |_| url.with(|url| R::from_str(url).ok()))
+}
+
+/// Like [`use_current_route`], but unmatched URLs resolve to the `#[fallback]` variant
+/// through the derived `From<impl AsRef<str>>`.
+pub fn use_current_route_or_fallback<R>() -> Memo<R>
+where
+    R: for<'a> From<&'a str> + Clone + PartialEq + Send + Sync + 'static,
+{
+    let url = use_current_url();
+    Memo::new(move |_| url.with(|url| R::from(url.as_str())))
+}
@@ -0,0 +1,73 @@
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::str::Utf8Error;
+
+/// Everything except the RFC 3986 unreserved characters (`A-Z a-z 0-9 - . _ ~`).
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// A percent-encoded part of a URL that doesn't decode to UTF-8.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("`{raw}` does not percent-decode to UTF-8")]
+pub struct DecodeError {
+    /// The part as it appeared in the URL.
+    pub raw: String,
+    source: Utf8Error,
+}
+
+fn decode(raw: &str, encoded: &str) -> Result<String, DecodeError> {
+    percent_decode_str(encoded)
+        .decode_utf8()
+        .map(|decoded| decoded.into_owned())
+        .map_err(|source| DecodeError { raw: raw.to_owned(), source })
+}
+
+/// Percent-encodes a single path segment so it cannot introduce `/`, `?` or `#`.
+///
+/// An empty segment would collapse into its neighbours, and `.` and `..` are resolved as
+/// dot-segments even when percent-encoded: the WHATWG URL standard treats `%2e` and `%2e%2e`
+/// like `.` and `..`. These three are written behind a lone `%` instead (`%`, `%.` and `%..`),
+/// which the encoder never emits otherwise, so every segment reads back as the same value.
+pub fn encode_path_segment(segment: &str) -> String {
+    match segment {
+        "" | "." | ".." => format!("%{segment}"),
+        segment => utf8_percent_encode(segment, COMPONENT).to_string(),
+    }
+}
+
+/// Percent-encodes a query key or value so it cannot introduce `&`, `=` or `#`.
+pub fn encode_query_component(component: &str) -> String {
+    utf8_percent_encode(component, COMPONENT).to_string()
+}
+
+/// Decodes a path segment produced by [`encode_path_segment`].
+pub fn decode_path_segment(segment: &str) -> Result<String, DecodeError> {
+    match segment.strip_prefix('%') {
+        Some(escaped @ ("" | "." | "..")) => Ok(escaped.to_owned()),
+        _ => decode(segment, segment),
+    }
+}
+
+/// Whether `segment`, as it appears in a URL, is the static segment `text` of a route path.
+/// Non-ASCII text like `über` arrives percent-encoded from the browser.
+pub fn path_segment_matches(segment: &str, text: &str) -> bool {
+    segment == text || (segment.contains('%') && decode_path_segment(segment).is_ok_and(|decoded| decoded == text))
+}
+
+/// Decodes a query key or value. A literal `+` is treated as a space, matching
+/// `application/x-www-form-urlencoded` as produced by browsers and `<Form>`.
+pub fn decode_query_component(component: &str) -> Result<String, DecodeError> {
+    decode(component, &component.replace('+', " "))
+}
+
+/// Percent-encodes a URL fragment (the part after `#`).
+pub fn encode_fragment(fragment: &str) -> String {
+    utf8_percent_encode(fragment, COMPONENT).to_string()
+}
+
+/// Decodes a URL fragment produced by [`encode_fragment`].
+pub fn decode_fragment(fragment: &str) -> Result<String, DecodeError> {
+    decode(fragment, fragment)
+}
@@ -0,0 +1,173 @@
+use crate::combine_paths::combine_paths;
+use crate::route_data::{use_route_base, use_variant_route};
+use crate::Routable;
+use leptos::children::ViewFn;
+use leptos::prelude::*;
+use leptos_router::components::Redirect;
+use std::fmt::Display;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// What a [`RouteGuard`] decided for the current route.
+#[derive(Clone)]
+pub enum GuardOutcome<R> {
+    /// Render the route's own view.
+    Allow,
+    /// Redirect to another route instead.
+    Redirect(R),
+    /// Render this view in place of the route's view.
+    Render(ViewFn),
+}
+
+impl<R> GuardOutcome<R> {
+    /// Shorthand for [`GuardOutcome::Render`] from a view function.
+    pub fn render<F, V>(view: F) -> Self
+    where
+        F: Fn() -> V + Send + Sync + 'static,
+        V: IntoView + 'static,
+    {
+        Self::Render(ViewFn::from(view))
+    }
+}
+
+/// An asynchronous check deciding whether a route may render, attached with
+/// `#[route(guard = MyGuard)]` (or on any other route attribute), or to every variant
+/// of an enum with `#[routes(guard = MyGuard)]`. Variants override the enum's guard with
+/// their own or opt out with `guard = none`; guards on parent routes also cover the
+/// nested enum rendered in their `<Outlet/>`.
+///
+/// A guard checks the enum it is attached to: guards on a nested enum see that enum's
+/// variant, wherever the enum is mounted, and redirect within the same parent route.
+///
+/// ```ignore
+/// struct RequireLogin;
+///
+/// impl RouteGuard for RequireLogin {
+///     type Route = AppRoutes;
+///
+///     async fn check(_route: &AppRoutes) -> GuardOutcome<AppRoutes> {
+///         match current_user().await {
+///             Ok(Some(_)) => GuardOutcome::Allow,
+///             _ => GuardOutcome::Redirect(AppRoutes::Login),
+///         }
+///     }
+/// }
+/// ```
+pub trait RouteGuard: 'static {
+    /// The route enum checked and redirected to, the enum the guard is attached to.
+    type Route: Routable + FromStr + Display + Clone + PartialEq + Send + Sync + 'static;
+
+    /// Decides whether `route` may render. Runs on the server as well, and again whenever
+    /// the guarded variant changes, not on query or child navigation it doesn't depend on.
+    fn check(route: &Self::Route) -> impl Future<Output = GuardOutcome<Self::Route>> + Send;
+
+    /// Rendered while [`RouteGuard::check`] is pending.
+    fn pending() -> impl IntoView {}
+}
+
+/// Allows a route only if both `A` and `B` allow it, checking `B` after `A`.
+/// Written `guard = all(A, B, ...)` in route attributes.
+pub struct AllOf<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> RouteGuard for AllOf<A, B>
+where
+    A: RouteGuard,
+    B: RouteGuard<Route = A::Route>,
+{
+    type Route = A::Route;
+
+    async fn check(route: &A::Route) -> GuardOutcome<A::Route> {
+        match A::check(route).await {
+            GuardOutcome::Allow => B::check(route).await,
+            outcome => outcome,
+        }
+    }
+
+    fn pending() -> impl IntoView {
+        A::pending()
+    }
+}
+
+/// Allows a route if either `A` or `B` allows it. When both refuse, `A`'s outcome is used.
+/// Written `guard = any(A, B, ...)` in route attributes.
+pub struct AnyOf<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> RouteGuard for AnyOf<A, B>
+where
+    A: RouteGuard,
+    B: RouteGuard<Route = A::Route>,
+{
+    type Route = A::Route;
+
+    async fn check(route: &A::Route) -> GuardOutcome<A::Route> {
+        match A::check(route).await {
+            GuardOutcome::Allow => GuardOutcome::Allow,
+            refused => match B::check(route).await {
+                GuardOutcome::Allow => GuardOutcome::Allow,
+                _ => refused,
+            },
+        }
+    }
+
+    fn pending() -> impl IntoView {
+        A::pending()
+    }
+}
+
+/// Renders `view` once the guard `G` allows the current route, redirecting or rendering
+/// the guard's view otherwise. This is what `guard = ...` route attributes expand to.
+///
+/// The check is keyed on the guarded `is_variant` route, like
+/// [`with_route_data`](crate::prelude::with_route_data): it re-runs when that variant's
+/// instance changes, and for `nested` (parent) routes only when their own path does. It
+/// runs during SSR too, so `SsrMode::Async` and `InOrder` routes render the checked view.
+///
+/// URLs the guard can't be asked about, because they don't parse into the guarded route,
+/// render the guarded enum's `#[fallback]` instead.
+///
+/// Redirect targets are resolved against the parent route the guarded enum is mounted
+/// under. Targets with a `#[query(return_to)]` field get the refused route filled in.
+pub fn guarded<G, R, F, V>(is_variant: fn(&R) -> bool, nested: bool, view: F) -> impl IntoView
+where
+    G: RouteGuard<Route = R>,
+    R: Routable + FromStr + Display + Clone + PartialEq + Send + Sync + 'static,
+    F: Fn() -> V + Send + Sync + 'static,
+    V: IntoView + 'static,
+{
+    let guarded_route = use_variant_route::<R>(is_variant, nested);
+    let base = use_route_base();
+    let outcome = AsyncDerived::new(move || {
+        let route = guarded_route.get();
+        async move {
+            match route {
+                Some(route) => G::check(&route).await,
+                // Matched by the router but not parsed, e.g. `/user/abc` for a numeric `:id`
+                None => GuardOutcome::render(R::fallback),
+            }
+        }
+    });
+
+    let view = Arc::new(view);
+    view! {
+        <Suspense fallback=G::pending>
+            {move || {
+                let view = Arc::clone(&view);
+                Suspend::new(async move {
+                    match outcome.await {
+                        GuardOutcome::Allow => view().into_any(),
+                        GuardOutcome::Redirect(mut route) => {
+                            if let Some(from) = guarded_route.get_untracked() {
+                                route.set_return_to(&from.to_string());
+                            }
+                            let path = combine_paths(&base.get_untracked(), &route.to_string());
+                            view! { <Redirect path=path /> }.into_any()
+                        }
+                        GuardOutcome::Render(render) => render.run(),
+                    }
+                })
+            }}
+        </Suspense>
+    }
+}
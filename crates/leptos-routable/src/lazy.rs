@@ -0,0 +1,21 @@
+use leptos::prelude::*;
+use std::future::Future;
+
+/// Renders the view of a `lazy` route, showing `loading` while its chunk fetches. This is
+/// what `lazy` route attributes expand to.
+///
+/// `load` is the generated `#[leptos::lazy]` split point. Without leptos' `csr` or `hydrate`
+/// feature nothing is split, so it resolves at once and `loading` is never shown.
+pub fn lazy_view<L, LV, F, Fut>(loading: L, load: F) -> impl IntoView
+where
+    L: FnOnce() -> LV + Send + 'static,
+    LV: IntoView + 'static,
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = AnyView> + Send + 'static,
+{
+    view! {
+        <Suspense fallback=loading>
+            {Suspend::new(load())}
+        </Suspense>
+    }
+}
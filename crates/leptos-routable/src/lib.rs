@@ -1,14 +1,165 @@
 use std::fmt::Debug;
 mod maybe_param;
+mod breadcrumbs;
+#[cfg(feature = "meta")]
+mod meta;
 mod combine_paths;
+mod current_route;
+mod encoding;
+mod guard;
+mod lazy;
+mod locale;
+mod navigate;
+mod parse_error;
+mod prefetch;
+mod route_link;
+mod route_query;
+mod return_to;
+mod route_data;
+mod route_table;
+pub mod sitemap;
+mod static_routes;
+
+/// Renders [`prelude::route_head`] for `#[routes(inject_title)]`.
+#[cfg(feature = "meta")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __route_head {
+    ($routes:ty) => {
+        $crate::prelude::route_head::<$routes>()
+    };
+}
+
+/// `#[routes(inject_title)]` without the `meta` feature, which `route_head` needs.
+#[cfg(not(feature = "meta"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __route_head {
+    ($routes:ty) => {
+        ::core::compile_error!("`#[routes(inject_title)]` requires the `meta` feature of `leptos-routable`")
+    };
+}
 
 pub trait Routable {
+    /// Every variant with a route attribute, in declaration order. Parent routes link to
+    /// the nested enum's table.
+    ///
+    /// A nested enum's table is shared by every parent it is mounted under, so paths are
+    /// stored as declared, relative to the parent route: `/settings` under `/dashboard`
+    /// stays `/settings`. [`Routable::route_entries`] has the full path patterns.
+    const ROUTE_TABLE: &'static [route_table::RouteInfo];
+
+    /// [`Routable::ROUTE_TABLE`] flattened, with full path patterns combined through nested
+    /// enums. Built on first use; each entry dereferences to its [`RouteInfo`](route_table::RouteInfo).
+    fn route_entries() -> &'static [route_table::RouteEntry];
+
+    /// Every route without fields, nested ones included, as enum values. Fallback
+    /// variants are skipped.
+    fn all_static_routes() -> impl Iterator<Item = Self>
+    where
+        Self: Sized;
+
+    /// Every route to prerender: [`Routable::all_static_routes`] plus the instances
+    /// listed by `#[route(static_params = "...")]` functions, nested enums included.
+    fn prerender_routes() -> Vec<Self>
+    where
+        Self: Sized;
+
+    /// [`Routable::prerender_routes`] as URL strings, without duplicates.
+    fn static_paths() -> Vec<String>
+    where
+        Self: Sized + ::std::fmt::Display,
+    {
+        let mut paths = Vec::new();
+        for route in Self::prerender_routes() {
+            let path = route.to_string();
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// The locales of `#[routes(locales = [...])]`, empty for enums without them.
+    const LOCALES: &'static [&'static str];
+
+    /// Whether `#[routes(inject_title)]` renders the route's head next to [`Routable::routes`].
+    /// Only root enums may set it, nested enums never render their own `routes`.
+    #[doc(hidden)]
+    const INJECT_TITLE: bool = false;
+
+    /// The href of this route built from the `path_<locale>` paths of `locale`, falling
+    /// back to `path` where a variant has none. Never carries a locale prefix.
+    fn localized_path(&self, locale: &str) -> String;
+
+    /// The href of this route in `locale`, e.g. `/de/einstellungen` for a variant declared
+    /// with `path = "/settings", path_de = "/einstellungen"`. The `/{locale}` prefix is only
+    /// added for one of [`Routable::LOCALES`]. `Display` keeps emitting the canonical path.
+    fn to_href_in(&self, locale: &str) -> String {
+        match Self::LOCALES.iter().find(|l| l.eq_ignore_ascii_case(locale)) {
+            Some(locale) => locale::prefix_locale(locale, &self.localized_path(locale)),
+            None => self.localized_path(locale),
+        }
+    }
+
+    /// Parses `input` like `FromStr`, also returning the locale of its `/{locale}` prefix.
+    ///
+    /// A prefixed URL only matches the paths registered for that locale, its `path_<locale>`
+    /// or `path` where a variant has none, and its error is returned as is when it doesn't
+    /// parse. An unprefixed one matches `path` and the `aliases`.
+    fn parse_localized(input: &str) -> Result<(Self, Option<&'static str>), parse_error::RouteParseError>
+    where
+        Self: Sized;
+
+    /// Parses `input` as the nested enum of a parent route matched under `locale`'s prefix.
+    /// Enums without their own [`Routable::LOCALES`] then only match that locale's paths, like
+    /// a prefixed URL does; with `None` they match every path as `FromStr` does.
+    #[doc(hidden)]
+    fn parse_in_locale(input: &str, locale: Option<&'static str>) -> Result<Self, parse_error::RouteParseError>
+    where
+        Self: Sized;
+
+    /// Variant names from this enum down to the innermost nested enum,
+    /// e.g. `["Dashboard", "Settings"]` for `AppRoutes::Dashboard(DashboardRoutes::Settings)`.
+    fn variant_chain(&self) -> Vec<&'static str>;
+
+    /// Starts the `loader`s of this route and the parents it is nested in, caching their
+    /// results for navigation, and preloads their `lazy` views. See [`prelude::prefetch`].
+    fn prefetch(&self);
+
+    /// [`Routable::prefetch`] of a route found at `url`, the full URL of the root route it
+    /// is nested in, which its loader results are cached under.
+    #[doc(hidden)]
+    fn prefetch_in(&self, url: &str);
+
+    /// Fills in the `#[query(return_to)]` field of this route, or of the nested route it wraps, by
+    /// parsing `from`. Routes without such a field, or with one already set, are left unchanged.
+    fn set_return_to(&mut self, from: &str);
+
+    /// The `title` of this route, formatted with the variant's fields.
+    /// Nested routes fall back to the parent's title when they have none.
+    fn title(&self) -> Option<String>;
+
+    /// The `description` of this route, formatted like [`Routable::title`].
+    fn description(&self) -> Option<String>;
+
+    /// Static `meta(key = "value")` pairs of the innermost route that declares any.
+    fn meta(&self) -> &'static [(&'static str, &'static str)];
+
+    /// One [`Breadcrumb`](prelude::Breadcrumb) per level of the route, outermost first.
+    fn breadcrumbs(&self) -> Vec<breadcrumbs::Breadcrumb<Self>>
+    where
+        Self: Sized;
+
     fn routes() -> impl ::leptos::IntoView;
 
     fn flat_routes() -> impl ::leptos::IntoView;
 
     fn fallback() -> impl ::leptos::IntoView;
 
+    /// The enum's routes nested under a parent route at `path`. `locale` is the prefix `path`
+    /// is registered under, which limits enums without their own [`Routable::LOCALES`] to
+    /// that locale's paths.
     fn parent_route<
         Path,
         View,
@@ -16,6 +167,7 @@ pub trait Routable {
         path: Path,
         view: View,
         ssr: ::leptos_router::SsrMode,
+        locale: Option<&'static str>,
     ) -> impl ::leptos_router::MatchNestedRoutes + Clone
     where
         Path: Send
@@ -40,6 +192,7 @@ pub trait Routable {
         fallback: ::leptos::children::ViewFn,
         redirect_path: RedirectPathFn,
         ssr: ::leptos_router::SsrMode,
+        locale: Option<&'static str>,
     ) -> impl ::leptos_router::MatchNestedRoutes + Clone
     where
         Path: Send
@@ -60,5 +213,25 @@ pub mod prelude {
     pub use leptos_routable_macro::*;
     pub use crate::maybe_param::*;
     pub use super::Routable;
+    pub use super::breadcrumbs::{Breadcrumb, Breadcrumbs, BreadcrumbsProps};
     pub use super::combine_paths::combine_paths;
+    pub use super::current_route::{use_current_route, use_current_route_or_fallback};
+    pub use super::encoding::*;
+    pub use super::guard::{guarded, AllOf, AnyOf, GuardOutcome, RouteGuard};
+    pub use super::lazy::lazy_view;
+    pub use super::locale::{prefix_locale, split_locale};
+    #[cfg(feature = "meta")]
+    pub use super::meta::route_head;
+    pub use super::navigate::{use_typed_navigate, use_typed_navigator, TypedNavigate};
+    pub use super::parse_error::{ErrorSource, IntoErrorSource, RouteParseError};
+    pub use super::prefetch::{prefetch, prefetch_route_data, set_prefetch_ttl, Prefetch, DEFAULT_PREFETCH_TTL};
+    pub use super::return_to::{
+        redirect_with_return_to, IntoRedirectTarget, RedirectPath, RedirectTarget, ReturnTo, RETURN_TO_KEY,
+    };
+    pub use super::route_link::{RouteLink, RouteLinkProps, RouteMatch};
+    pub use super::route_data::{provide_route_base, resolve_route_path, use_route_data, with_route_data};
+    pub use super::route_query::*;
+    pub use super::route_table::{route_entries, ParamInfo, RouteEntry, RouteInfo, RouteKind};
+    pub use super::sitemap::{ChangeFreq, RobotsOptions, Sitemap, SitemapOptions};
+    pub use super::static_routes::static_params_map;
 }
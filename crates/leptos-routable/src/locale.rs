@@ -0,0 +1,19 @@
+/// Splits a leading `/{locale}` segment for one of `locales` off `input`, returning the
+/// locale and the remaining URL as an absolute path (`/de?x=1` becomes `("de", "/?x=1")`).
+pub fn split_locale(input: &str, locales: &'static [&'static str]) -> Option<(&'static str, String)> {
+    let path = input.strip_prefix('/')?;
+    let end = path.find(['/', '?', '#']).unwrap_or(path.len());
+    let locale = locales.iter().find(|locale| locale.eq_ignore_ascii_case(&path[..end]))?;
+    let rest = path[end..].trim_start_matches('/');
+    Some((locale, format!("/{rest}")))
+}
+
+/// Prefixes the href `path` with `/{locale}`, without leaving a trailing slash on the root.
+pub fn prefix_locale(locale: &str, path: &str) -> String {
+    let rest = path.trim_start_matches('/');
+    if rest.is_empty() || rest.starts_with(['?', '#']) {
+        format!("/{locale}{rest}")
+    } else {
+        format!("/{locale}/{rest}")
+    }
+}
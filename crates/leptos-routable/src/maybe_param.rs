@@ -1,13 +1,16 @@
 use leptos::prelude::*;
-use leptos_router::hooks::{use_params_map, use_query_map};
+use leptos_router::hooks::{use_location, use_params_map, use_query_map};
+use leptos_router::params::ParamsMap;
 use std::str::FromStr;
+use crate::encoding::decode_fragment;
+use crate::route_query::RouteQuery;
 
 /// Holds the parsed state of a route or query parameter.
 /// It may be missing, unparseable, or a valid value of type [`T`].
 #[derive(Debug, PartialEq, Clone, Eq)]
 pub enum ParamValue<T>
 where
-    T: FromStr + Send + Clone + Sync + 'static + PartialEq + Eq,
+    T: FromStr + Send + Clone + Sync + 'static + PartialEq,
 {
     /// The parameter was not found or is an empty string.
     Missing,
@@ -19,7 +22,7 @@ where
 
 impl<T> ParamValue<T>
 where
-    T: FromStr + Send + Clone + Sync + 'static + PartialEq + Eq,
+    T: FromStr + Send + Clone + Sync + 'static + PartialEq,
 {
     /// Returns `Some(T)` if this is a valid parsed value, or [`None`] otherwise.
     pub fn ok(self) -> Option<T> {
@@ -54,7 +57,7 @@ pub enum ParamError {
 /// This can be implemented by route params, query params, cookies, etc.
 pub trait TypedParam<T>
 where
-    T: FromStr + Send + Clone + Sync + 'static + PartialEq + Eq,
+    T: FromStr + Send + Clone + Sync + 'static + PartialEq,
 {
     /// Creates a new typed param with the specified key.
     fn new(key: &'static str) -> Self;
@@ -78,48 +81,32 @@ where
     fn unwrap_or(&self, default: T) -> Memo<T>;
 }
 
-/// Generates a struct that uses a reactive [`Memo`] to track and parse
-/// a particular parameter key from either [`use_params_map`] or [`use_query_map`].
+/// Generates a struct that uses a reactive [`Memo`] to track and parse a value
+/// from the current location. The `new` block builds that memo; `keyed` types
+/// also implement [`TypedParam`] and `From<&'static str>`, `default` ones [`Default`].
 macro_rules! define_typed_param_type {
     (
-        $type_name:ident,
-        $map_fn:path
+        $(#[$meta:meta])*
+        $type_name:ident<T $(: $bound:path)?>, $kind:ident,
+        $(#[$new_meta:meta])*
+        fn new($($arg:ident: $arg_ty:ty),*) $memo:block
     ) => {
-        /// A reactive parameter that automatically re-parses a specified key
-        /// whenever the underlying data source changes.
+        $(#[$meta])*
         #[derive(Debug, PartialEq, Clone, Eq)]
         pub struct $type_name<T>
         where
-            T: FromStr + Send + Clone + Sync + 'static + PartialEq + Eq,
+            T: $($bound +)? FromStr + Send + Clone + Sync + 'static + PartialEq,
         {
-            key: &'static str,
             memo: Memo<ParamValue<T>>,
         }
 
         impl<T> $type_name<T>
         where
-            T: FromStr + Send + Clone + Sync + 'static + PartialEq + Eq,
+            T: $($bound +)? FromStr + Send + Clone + Sync + 'static + PartialEq,
         {
-            /// Creates a new instance linked to the specified parameter key.
-            /// The param is parsed and stored in a [`Memo`] for reactive updates.
-            pub fn new(key: &'static str) -> Self {
-                let map_memo = $map_fn();
-                let memo = Memo::new(move |_| {
-                    let raw = map_memo
-                        .get()
-                        .get_str(key)
-                        .map(|s| s.to_string());
-
-                    match raw {
-                        None => ParamValue::Missing,
-                        Some(ref s) if s.is_empty() => ParamValue::Missing,
-                        Some(s) => match s.parse::<T>() {
-                            Ok(parsed) => ParamValue::Value(parsed),
-                            Err(_) => ParamValue::ParseError(s),
-                        },
-                    }
-                });
-                Self { key, memo }
+            $(#[$new_meta])*
+            pub fn new($($arg: $arg_ty),*) -> Self {
+                Self { memo: $memo }
             }
 
             /// Returns the current [`ParamValue<T>`].
@@ -129,38 +116,42 @@ macro_rules! define_typed_param_type {
 
             /// Returns a [`Memo<bool>`] that is true if the param is missing or empty.
             pub fn is_missing(&self) -> Memo<bool> {
-                let memo = self.memo.clone();
+                let memo = self.memo;
                 Memo::new(move |_| matches!(memo.get(), ParamValue::Missing))
             }
 
             /// Returns a [`Memo<bool>`] that is true if the param failed to parse.
             pub fn is_parse_error(&self) -> Memo<bool> {
-                let memo = self.memo.clone();
+                let memo = self.memo;
                 Memo::new(move |_| matches!(memo.get(), ParamValue::ParseError(_)))
             }
 
             /// Returns a [`Memo<bool>`] that is true if the param was parsed successfully.
             pub fn is_value(&self) -> Memo<bool> {
-                let memo = self.memo.clone();
+                let memo = self.memo;
                 Memo::new(move |_| matches!(memo.get(), ParamValue::Value(_)))
             }
 
             /// Returns a [`Memo<Option<T>>`] that is `Some(T)` if parsed, or [`None`] otherwise.
             pub fn ok(&self) -> Memo<Option<T>> {
-                let memo = self.memo.clone();
-                Memo::new(move |_| memo.get().clone().ok())
+                let memo = self.memo;
+                Memo::new(move |_| memo.get().ok())
             }
 
             /// Returns a [`Memo<T>`] that either holds the parsed value or a default.
             pub fn unwrap_or(&self, default: T) -> Memo<T> {
-                let memo = self.memo.clone();
-                Memo::new(move |_| memo.get().clone().unwrap_or(default.clone()))
+                let memo = self.memo;
+                Memo::new(move |_| memo.get().unwrap_or(default.clone()))
             }
         }
 
+        define_typed_param_type!(@$kind $type_name<T $(: $bound)?>);
+    };
+
+    (@keyed $type_name:ident<T $(: $bound:path)?>) => {
         impl<T> TypedParam<T> for $type_name<T>
         where
-            T: FromStr + Send + Clone + Sync + 'static + PartialEq + Eq,
+            T: $($bound +)? FromStr + Send + Clone + Sync + 'static + PartialEq,
         {
             fn new(key: &'static str) -> Self {
                 Self::new(key)
@@ -193,7 +184,7 @@ macro_rules! define_typed_param_type {
 
         impl<T> From<&'static str> for $type_name<T>
         where
-            T: FromStr + Send + Clone + Sync + 'static + PartialEq + Eq,
+            T: $($bound +)? FromStr + Send + Clone + Sync + 'static + PartialEq,
         {
             /// Allows creating a typed param from a string literal key.
             fn from(key: &'static str) -> Self {
@@ -201,8 +192,93 @@ macro_rules! define_typed_param_type {
             }
         }
     };
+
+    (@default $type_name:ident<T $(: $bound:path)?>) => {
+        impl<T> Default for $type_name<T>
+        where
+            T: $($bound +)? FromStr + Send + Clone + Sync + 'static + PartialEq,
+        {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+/// Parses `key` of a params or query map, an empty value counts as missing.
+fn parse_key<T>(map: Memo<ParamsMap>, key: &'static str) -> Memo<ParamValue<T>>
+where
+    T: FromStr + Send + Clone + Sync + 'static + PartialEq,
+{
+    Memo::new(move |_| {
+        let raw = map.get().get_str(key).map(|s| s.to_string());
+        match raw {
+            None => ParamValue::Missing,
+            Some(ref s) if s.is_empty() => ParamValue::Missing,
+            Some(s) => match s.parse::<T>() {
+                Ok(parsed) => ParamValue::Value(parsed),
+                Err(_) => ParamValue::ParseError(s),
+            },
+        }
+    })
 }
 
-// Provides typed route params and query params using the macro.
-define_typed_param_type!(MaybeParam, use_params_map);
-define_typed_param_type!(MaybeQuery, use_query_map);
+define_typed_param_type! {
+    /// A reactive route param that automatically re-parses a specified key
+    /// whenever the matched params change.
+    MaybeParam<T>, keyed,
+    /// Creates a new instance linked to the specified route param key.
+    fn new(key: &'static str) { parse_key(use_params_map(), key) }
+}
+
+define_typed_param_type! {
+    /// A reactive query param that automatically re-parses a specified key
+    /// whenever the query string changes.
+    MaybeQuery<T>, keyed,
+    /// Creates a new instance linked to the specified query key.
+    fn new(key: &'static str) { parse_key(use_query_map(), key) }
+}
+
+define_typed_param_type! {
+    /// A reactive view of a whole [`RouteQuery`] struct, re-parsed from the raw
+    /// query string whenever the URL changes.
+    ///
+    /// An empty query is not [`ParamValue::Missing`]; it parses into a struct whose
+    /// optional fields are [`None`]. Only a failing field yields [`ParamValue::ParseError`],
+    /// carrying the raw query string.
+    MaybeQueryParams<T: RouteQuery>, default,
+    /// Creates a new instance tracking the current location's query string.
+    fn new() {
+        let search = use_location().search;
+        Memo::new(move |_| {
+            let raw = search.get();
+            match T::from_query_string(&raw) {
+                Ok(parsed) => ParamValue::Value(parsed),
+                Err(_) => ParamValue::ParseError(raw),
+            }
+        })
+    }
+}
+
+define_typed_param_type! {
+    /// A reactive URL fragment (`#anchor`), parsed as [`T`] whenever the location changes.
+    MaybeFragment<T>, default,
+    /// Creates a new instance tracking the current location's hash.
+    fn new() {
+        let hash = use_location().hash;
+        Memo::new(move |_| {
+            let hash = hash.get();
+            let raw = match decode_fragment(hash.trim_start_matches('#')) {
+                Ok(raw) => raw,
+                Err(err) => return ParamValue::ParseError(err.raw),
+            };
+            if raw.is_empty() {
+                return ParamValue::Missing;
+            }
+            match raw.parse::<T>() {
+                Ok(parsed) => ParamValue::Value(parsed),
+                Err(_) => ParamValue::ParseError(raw),
+            }
+        })
+    }
+}
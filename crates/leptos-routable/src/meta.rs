@@ -0,0 +1,31 @@
+use crate::current_route::use_current_route;
+use crate::Routable;
+use leptos::prelude::*;
+use leptos_meta::{Meta, Title};
+use std::str::FromStr;
+
+/// Renders `<Title>` and `<Meta>` tags for the current route from its `title`,
+/// `description` and `meta(...)` attributes. Routes without metadata render nothing.
+///
+/// Injected next to the routes by `#[routes(inject_title)]`; requires
+/// [`leptos_meta::provide_meta_context`] higher up in the tree.
+pub fn route_head<R>() -> impl IntoView
+where
+    R: Routable + FromStr + Clone + PartialEq + Send + Sync + 'static,
+{
+    let current = use_current_route::<R>();
+    move || {
+        current.get().map(|route| {
+            let title = route.title().map(|text| view! { <Title text=text/> });
+            let description = route
+                .description()
+                .map(|content| view! { <Meta name="description" content=content/> });
+            let meta = route
+                .meta()
+                .iter()
+                .map(|(name, content)| view! { <Meta name=*name content=*content/> })
+                .collect_view();
+            (title, description, meta)
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use crate::return_to::RETURN_TO_KEY;
+use crate::Routable;
+use leptos::prelude::GetUntracked;
+use leptos_router::location::{Location, State};
+use leptos_router::NavigateOptions;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::Arc;
+
+type NavigateFn = Arc<dyn Fn(&str, NavigateOptions) + Send + Sync>;
+
+/// Navigates using route enums instead of strings, so every call site is
+/// checked against the current shape of `R`.
+pub struct TypedNavigate<R> {
+    navigate: NavigateFn,
+    location: Location,
+    _route: PhantomData<fn(R)>,
+}
+
+impl<R> Clone for TypedNavigate<R> {
+    fn clone(&self) -> Self {
+        Self {
+            navigate: self.navigate.clone(),
+            location: self.location.clone(),
+            _route: PhantomData,
+        }
+    }
+}
+
+impl<R> TypedNavigate<R>
+where
+    R: Routable + Display,
+{
+    /// Navigates to `route` with explicit [`NavigateOptions`].
+    pub fn navigate(&self, route: R, options: NavigateOptions) {
+        (self.navigate)(&route.to_string(), options)
+    }
+
+    /// Pushes `route` onto the history stack.
+    pub fn push(&self, route: R) {
+        self.navigate(route, NavigateOptions::default())
+    }
+
+    /// Replaces the current history entry with `route`.
+    pub fn replace(&self, route: R) {
+        self.navigate(
+            route,
+            NavigateOptions {
+                replace: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Pushes `route` along with history [`State`].
+    pub fn with_state(&self, route: R, state: State) {
+        self.navigate(
+            route,
+            NavigateOptions {
+                state,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Goes back one entry in the browser history. Does nothing outside the browser.
+    pub fn back(&self) {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(history) = leptos::web_sys::window().and_then(|w| w.history().ok()) {
+            _ = history.back();
+        }
+    }
+}
+
+impl<R> TypedNavigate<R>
+where
+    R: Routable + Display + FromStr,
+{
+    /// The route in the current URL's `return_to` query parameter, if it parses as `R`.
+    /// Anything else, such as an external URL, is ignored.
+    pub fn return_to(&self) -> Option<R> {
+        self.location
+            .query
+            .get_untracked()
+            .get_str(RETURN_TO_KEY)
+            .and_then(|from| R::from_str(from).ok())
+    }
+
+    /// Replaces the current history entry with the route the user was redirected away
+    /// from, or with `fallback` when there is none. Call this after a successful login.
+    pub fn return_or(&self, fallback: R) {
+        self.replace(self.return_to().unwrap_or(fallback))
+    }
+}
+
+/// Returns a [`TypedNavigate`] for `R`, with helpers for replace, back, state and `return_to`.
+/// Must be called inside a `<Router>`.
+pub fn use_typed_navigator<R>() -> TypedNavigate<R>
+where
+    R: Routable + Display,
+{
+    TypedNavigate {
+        navigate: Arc::new(leptos_router::hooks::use_navigate()),
+        location: leptos_router::hooks::use_location(),
+        _route: PhantomData,
+    }
+}
+
+/// Returns a closure that navigates to a route enum, the typed counterpart of
+/// [`use_navigate`](leptos_router::hooks::use_navigate).
+pub fn use_typed_navigate<R>() -> impl Fn(R, NavigateOptions) + Clone + Send + Sync
+where
+    R: Routable + Display,
+{
+    let navigator = use_typed_navigator::<R>();
+    move |route: R, options: NavigateOptions| navigator.navigate(route, options)
+}
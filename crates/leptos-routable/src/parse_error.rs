@@ -0,0 +1,144 @@
+use crate::encoding::DecodeError;
+use std::error::Error;
+use std::str::FromStr;
+
+/// The field type's own `FromStr` error, kept as the `source` of the parse errors.
+type BoxedError = Box<dyn Error + Send + Sync>;
+
+/// Explains why the derived [`FromStr`] implementation rejected a URL.
+///
+/// Variant names are reported as `Enum::Variant`, fields by their declared name,
+/// and raw values after percent-decoding. The field type's `FromStr` error is kept
+/// as `source` when it implements [`Error`], and left out otherwise. Values that don't
+/// percent-decode to UTF-8 are reported as they appear in the URL, with a
+/// [`DecodeError`] source.
+#[derive(Debug, thiserror::Error)]
+pub enum RouteParseError {
+    /// No variant's path pattern matched the URL.
+    #[error("no route matches path `{path}`")]
+    NoMatch { path: String },
+
+    /// A path segment matched a variant but could not be parsed into the field's type.
+    #[error("failed to parse path param `{field}` of `{variant}` from `{raw}`")]
+    ParamParse {
+        variant: &'static str,
+        field: &'static str,
+        raw: String,
+        source: Option<BoxedError>,
+    },
+
+    /// A query value was present but could not be parsed into the field's type.
+    #[error("failed to parse query param `{field}` of `{variant}` from `{raw}`")]
+    QueryParse {
+        variant: &'static str,
+        field: &'static str,
+        raw: String,
+        source: Option<BoxedError>,
+    },
+
+    /// The URL fragment was present but could not be parsed into the `#[fragment]` field's type.
+    #[error("failed to parse fragment `{field}` of `{variant}` from `{raw}`")]
+    FragmentParse {
+        variant: &'static str,
+        field: &'static str,
+        raw: String,
+        source: Option<BoxedError>,
+    },
+
+    /// A required `#[query]` field was absent from the query string.
+    #[error("missing required query param `{key}` for `{variant}`")]
+    MissingQuery {
+        variant: &'static str,
+        field: &'static str,
+        key: &'static str,
+    },
+
+    /// The parent path matched, but the remainder was rejected by the nested enum.
+    #[error("nested route under `{parent}` failed: {inner}")]
+    Nested {
+        parent: &'static str,
+        #[source]
+        inner: Box<RouteParseError>,
+    },
+}
+
+impl RouteParseError {
+    /// Decodes and parses a path param, mapping failures to [`RouteParseError::ParamParse`].
+    #[doc(hidden)]
+    pub fn parse_param<T: FromStr>(
+        variant: &'static str,
+        field: &'static str,
+        decoded: Result<String, DecodeError>,
+        source: impl FnOnce(T::Err) -> Option<BoxedError>,
+    ) -> Result<T, Self> {
+        let raw = match decoded {
+            Ok(raw) => raw,
+            Err(err) => return Err(Self::ParamParse { variant, field, raw: err.raw.clone(), source: Some(Box::new(err)) }),
+        };
+        raw.parse::<T>().map_err(|e| Self::ParamParse { variant, field, raw, source: source(e) })
+    }
+
+    /// Decodes and parses a query value, mapping failures to [`RouteParseError::QueryParse`].
+    #[doc(hidden)]
+    pub fn parse_query<T: FromStr>(
+        variant: &'static str,
+        field: &'static str,
+        decoded: Result<String, DecodeError>,
+        source: impl FnOnce(T::Err) -> Option<BoxedError>,
+    ) -> Result<T, Self> {
+        let raw = match decoded {
+            Ok(raw) => raw,
+            Err(err) => return Err(Self::QueryParse { variant, field, raw: err.raw.clone(), source: Some(Box::new(err)) }),
+        };
+        raw.parse::<T>().map_err(|e| Self::QueryParse { variant, field, raw, source: source(e) })
+    }
+
+    /// Decodes and parses a URL fragment, mapping failures to [`RouteParseError::FragmentParse`].
+    #[doc(hidden)]
+    pub fn parse_fragment<T: FromStr>(
+        variant: &'static str,
+        field: &'static str,
+        decoded: Result<String, DecodeError>,
+        source: impl FnOnce(T::Err) -> Option<BoxedError>,
+    ) -> Result<T, Self> {
+        let raw = match decoded {
+            Ok(raw) => raw,
+            Err(err) => return Err(Self::FragmentParse { variant, field, raw: err.raw.clone(), source: Some(Box::new(err)) }),
+        };
+        raw.parse::<T>().map_err(|e| Self::FragmentParse { variant, field, raw, source: source(e) })
+    }
+
+    /// Returns the innermost error, following [`RouteParseError::Nested`] chains.
+    pub fn root_cause(&self) -> &RouteParseError {
+        match self {
+            Self::Nested { inner, .. } => inner.root_cause(),
+            other => other,
+        }
+    }
+}
+
+/// Wraps a field type's `FromStr` error for [`IntoErrorSource`].
+#[doc(hidden)]
+pub struct ErrorSource<E>(pub E);
+
+/// Turns a wrapped `FromStr` error into a parse error `source`.
+///
+/// Called as `ErrorSource(e).into_error_source()` on a concrete error type, which
+/// picks the boxing impl for [`Error`] types and falls back to the `&ErrorSource`
+/// impl, which drops the error, for anything else (like `()`).
+#[doc(hidden)]
+pub trait IntoErrorSource {
+    fn into_error_source(self) -> Option<BoxedError>;
+}
+
+impl<E: Error + Send + Sync + 'static> IntoErrorSource for ErrorSource<E> {
+    fn into_error_source(self) -> Option<BoxedError> {
+        Some(Box::new(self.0))
+    }
+}
+
+impl<E> IntoErrorSource for &ErrorSource<E> {
+    fn into_error_source(self) -> Option<BoxedError> {
+        None
+    }
+}
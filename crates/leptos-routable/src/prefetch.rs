@@ -0,0 +1,199 @@
+use crate::Routable;
+use futures::future::{BoxFuture, FutureExt, Shared};
+use leptos::prelude::*;
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{js_sys, IntersectionObserver, IntersectionObserverEntry};
+
+/// How long prefetched loader results are reused, unless changed with [`set_prefetch_ttl`].
+pub const DEFAULT_PREFETCH_TTL: Duration = Duration::from_secs(30);
+
+/// When a [`RouteLink`](crate::prelude::RouteLink) prefetches its route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Prefetch {
+    /// Only once the link is followed.
+    #[default]
+    None,
+    /// When the link is hovered, focused or touched.
+    Hover,
+    /// When the link scrolls into view.
+    Visible,
+}
+
+pub(crate) type SharedData<T> = Shared<BoxFuture<'static, T>>;
+
+/// Loader results keyed by the route enum they were loaded for and the full URL of the
+/// route, so nested enums don't share results across parents, e.g. `/t/acme/users` and
+/// `/t/beta/users`. Times are passed in, so the cache itself works on any target.
+struct PrefetchCache {
+    ttl: Duration,
+    entries: HashMap<(TypeId, String), Prefetched>,
+}
+
+struct Prefetched {
+    at: Duration,
+    data: Box<dyn Any>,
+}
+
+impl PrefetchCache {
+    fn new() -> Self {
+        Self { ttl: DEFAULT_PREFETCH_TTL, entries: HashMap::new() }
+    }
+
+    fn is_fresh(ttl: Duration, prefetched: &Prefetched, now: Duration) -> bool {
+        now.saturating_sub(prefetched.at) < ttl
+    }
+
+    /// The fresh `D` cached for `R` at `url`.
+    fn get<R: 'static, D: Clone + 'static>(&self, url: &str, now: Duration) -> Option<D> {
+        self.entries
+            .get(&(TypeId::of::<R>(), url.to_owned()))
+            .filter(|prefetched| Self::is_fresh(self.ttl, prefetched, now))
+            .and_then(|prefetched| prefetched.data.downcast_ref::<D>())
+            .cloned()
+    }
+
+    /// Caches `data` for `R` at `url`, dropping the entries that went stale.
+    fn insert<R: 'static, D: 'static>(&mut self, url: &str, data: D, now: Duration) {
+        let ttl = self.ttl;
+        self.entries.retain(|_, prefetched| Self::is_fresh(ttl, prefetched, now));
+        self.entries
+            .insert((TypeId::of::<R>(), url.to_owned()), Prefetched { at: now, data: Box::new(data) });
+    }
+}
+
+thread_local! {
+    static PREFETCHED: RefCell<PrefetchCache> = RefCell::new(PrefetchCache::new());
+}
+
+/// Time since the Unix epoch, from the browser's clock.
+#[cfg(target_arch = "wasm32")]
+fn now() -> Duration {
+    Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now() -> Duration {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Warms `route` ahead of navigation: runs the loaders of its variant and the parents it is
+/// nested in, and preloads their lazy views. Prefetching only happens in the browser.
+pub fn prefetch<R: Routable>(route: R) {
+    route.prefetch();
+}
+
+/// Sets how long prefetched loader results are reused, [`DEFAULT_PREFETCH_TTL`] by default.
+pub fn set_prefetch_ttl(ttl: Duration) {
+    PREFETCHED.with_borrow_mut(|prefetched| prefetched.ttl = ttl);
+}
+
+/// Starts `loader` for `route` unless a fresh result is already cached for the full `url`
+/// it is found at. This is what [`Routable::prefetch`] expands to for variants with a `loader`.
+pub fn prefetch_route_data<R, T, L, Fut>(url: &str, route: R, loader: L)
+where
+    R: 'static,
+    T: Clone + Send + Sync + 'static,
+    L: FnOnce(R) -> Fut,
+    Fut: Future<Output = T> + Send + 'static,
+{
+    if !cfg!(target_arch = "wasm32") || prefetched::<R, T>(url).is_some() {
+        return;
+    }
+    let data: SharedData<T> = loader(route).boxed().shared();
+    PREFETCHED.with_borrow_mut(|prefetched| prefetched.insert::<R, _>(url, data.clone(), now()));
+    leptos::task::spawn_local(data.map(drop));
+}
+
+/// The prefetched, possibly still loading, result of the `R` loader at `url` if it is fresh.
+pub(crate) fn prefetched<R, T>(url: &str) -> Option<SharedData<T>>
+where
+    R: 'static,
+    T: Clone + Send + Sync + 'static,
+{
+    PREFETCHED.with_borrow(|prefetched| prefetched.get::<R, SharedData<T>>(url, now()))
+}
+
+/// Calls `f` once `element` first scrolls into view, until the current owner is cleaned up.
+pub(crate) fn on_visible(element: &web_sys::Element, f: impl FnOnce() + 'static) {
+    let mut f = Some(f);
+    let callback = Closure::<dyn FnMut(js_sys::Array, IntersectionObserver)>::new(
+        move |entries: js_sys::Array, observer: IntersectionObserver| {
+            let visible = entries
+                .iter()
+                .any(|entry| entry.unchecked_into::<IntersectionObserverEntry>().is_intersecting());
+            if let (true, Some(f)) = (visible, f.take()) {
+                observer.disconnect();
+                f();
+            }
+        },
+    );
+    let Ok(observer) = IntersectionObserver::new(callback.as_ref().unchecked_ref()) else {
+        return;
+    };
+    observer.observe(element);
+    // Dropped with the owner, after the cleanup below disconnected the observer
+    let observer = StoredValue::new_local((observer, callback));
+    on_cleanup(move || {
+        observer.try_with_value(|(observer, _)| observer.disconnect());
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn test_prefetch_cache_expires_after_ttl() {
+        let mut cache = PrefetchCache::new();
+        cache.ttl = 10 * SECOND;
+        cache.insert::<u8, _>("/user/1", 1u32, 100 * SECOND);
+        assert_eq!(cache.get::<u8, u32>("/user/1", 100 * SECOND), Some(1));
+        assert_eq!(cache.get::<u8, u32>("/user/1", 109 * SECOND), Some(1));
+        assert_eq!(cache.get::<u8, u32>("/user/1", 110 * SECOND), None);
+
+        // Stale entries are dropped on the next insert
+        cache.insert::<u8, _>("/user/2", 2u32, 120 * SECOND);
+        assert_eq!(cache.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_prefetch_cache_keys_by_enum_and_full_url() {
+        let mut cache = PrefetchCache::new();
+        cache.insert::<u8, _>("/t/acme/users", 1u32, SECOND);
+        assert_eq!(cache.get::<u8, u32>("/t/beta/users", SECOND), None);
+        assert_eq!(cache.get::<u16, u32>("/t/acme/users", SECOND), None);
+        assert_eq!(cache.get::<u8, u32>("/t/acme/users", SECOND), Some(1));
+    }
+
+    #[test]
+    fn test_prefetch_cache_misses_other_data_types() {
+        let mut cache = PrefetchCache::new();
+        cache.insert::<u8, _>("/user/1", 1u32, SECOND);
+        assert_eq!(cache.get::<u8, String>("/user/1", SECOND), None);
+    }
+
+    #[test]
+    fn test_prefetch_route_data_outside_browser_is_noop() {
+        let called = std::rc::Rc::new(Cell::new(false));
+        let loader = {
+            let called = called.clone();
+            move |_| {
+                called.set(true);
+                async { 1u32 }
+            }
+        };
+        prefetch_route_data("/user/1", 1u8, loader);
+        assert!(!called.get());
+        assert!(prefetched::<u8, u32>("/user/1").is_none());
+    }
+}
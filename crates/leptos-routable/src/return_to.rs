@@ -0,0 +1,120 @@
+use crate::Routable;
+use leptos::prelude::GetUntracked;
+use std::fmt::{self, Display};
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// The query key filled in by guard and protected-route redirects.
+pub const RETURN_TO_KEY: &str = "return_to";
+
+/// A route carried through a redirect, declared on the redirect target as
+/// `#[query(return_to)] return_to: Option<ReturnTo<AppRoutes>>`.
+///
+/// Boxed so the route enum can refer to itself; written and parsed through the
+/// route's own `Display`/`FromStr`. A `return_to` that doesn't parse, such as an
+/// external URL, is read as `None` instead of rejecting the whole route.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReturnTo<R>(Box<R>);
+
+impl<R> ReturnTo<R> {
+    pub fn new(route: R) -> Self {
+        Self(Box::new(route))
+    }
+
+    pub fn into_inner(self) -> R {
+        *self.0
+    }
+}
+
+impl<R> Deref for ReturnTo<R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        &self.0
+    }
+}
+
+impl<R: Display> Display for ReturnTo<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<R: FromStr> FromStr for ReturnTo<R> {
+    type Err = R::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        R::from_str(s).map(Self::new)
+    }
+}
+
+/// A `redirect_path` of `#[protected_route]`/`#[protected_parent_route]` variants.
+///
+/// Routes are given the current location through [`Routable::set_return_to`], which only
+/// fills in a `#[query(return_to)]` field; plain string paths are kept as they are.
+pub trait RedirectTarget {
+    fn into_href(self, from: &str) -> String;
+}
+
+impl<R: Routable + Display> RedirectTarget for R {
+    fn into_href(mut self, from: &str) -> String {
+        self.set_return_to(from);
+        self.to_string()
+    }
+}
+
+impl RedirectTarget for String {
+    fn into_href(self, _from: &str) -> String {
+        self
+    }
+}
+
+impl RedirectTarget for &str {
+    fn into_href(self, _from: &str) -> String {
+        self.to_owned()
+    }
+}
+
+/// Wraps the value returned by a `redirect_path` for [`IntoRedirectTarget`].
+#[doc(hidden)]
+pub struct RedirectPath<T>(pub T);
+
+/// Picks the [`RedirectTarget`] of a `redirect_path` value.
+///
+/// Called as `RedirectPath(path).into_redirect_target()` on a concrete type: routes are
+/// redirected to as they are, to be given a `return_to`, while any other `Display` value
+/// falls back to the `&RedirectPath` impl and is redirected to as its string.
+#[doc(hidden)]
+pub trait IntoRedirectTarget {
+    type Target: RedirectTarget;
+
+    fn into_redirect_target(self) -> Self::Target;
+}
+
+impl<R: Routable + Display> IntoRedirectTarget for RedirectPath<R> {
+    type Target = R;
+
+    fn into_redirect_target(self) -> R {
+        self.0
+    }
+}
+
+impl<T: Display> IntoRedirectTarget for &RedirectPath<T> {
+    type Target = String;
+
+    fn into_redirect_target(self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// The `redirect_path` of `#[protected_route]`/`#[protected_parent_route]` variants: `target`
+/// carrying the current location as its `return_to`, if it is a route that declares one.
+pub fn redirect_with_return_to(target: impl RedirectTarget) -> String {
+    let location = leptos_router::hooks::use_location();
+    let search = location.search.get_untracked();
+    let from = match search.trim_start_matches('?') {
+        "" => location.pathname.get_untracked(),
+        search => format!("{}?{search}", location.pathname.get_untracked()),
+    };
+    target.into_href(&from)
+}
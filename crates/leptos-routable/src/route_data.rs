@@ -0,0 +1,219 @@
+use crate::combine_paths::combine_paths;
+use crate::current_route::use_current_url;
+use crate::prefetch::prefetched;
+use futures::future::FutureExt;
+use leptos::prelude::*;
+use leptos_router::hooks::use_matched;
+use serde::{de::DeserializeOwned, Serialize};
+use std::future::Future;
+use std::str::FromStr;
+
+/// The path matched by the closest parent route, which nested route enums parse their part
+/// of the URL after.
+#[derive(Clone, Copy)]
+struct RouteBase(Memo<String>);
+
+/// Marks the current route as the base of its nested enum's routes. Parent route views are
+/// wrapped in this so loaders further down receive their own enum's variant.
+pub fn provide_route_base() {
+    provide_context(RouteBase(use_matched()));
+}
+
+/// The path matched by the closest parent route, empty outside of nested enums.
+pub(crate) fn use_route_base() -> Signal<String> {
+    let base = use_context::<RouteBase>();
+    Signal::derive(move || base.map(|base| base.0.get()).unwrap_or_default())
+}
+
+/// `path`, relative to the closest parent route like a nested enum's `Display`, resolved to
+/// the full path. Outside of nested enums it is returned as it is.
+pub fn resolve_route_path(path: &str) -> String {
+    combine_paths(&use_route_base().get_untracked(), path)
+}
+
+/// The current location parsed as `R` relative to the closest parent route, so nested enums
+/// see their own part of the URL. [`None`] if it doesn't parse.
+pub(crate) fn use_relative_route<R>() -> Memo<Option<R>>
+where
+    R: FromStr + Clone + PartialEq + Send + Sync + 'static,
+{
+    let base = use_route_base();
+    let url = use_current_url();
+    Memo::new(move |_| {
+        let base = base.get();
+        url.with(|url| R::from_str(&strip_route_base(&base, url)?).ok())
+    })
+}
+
+struct RouteData<T: Send + Sync + 'static>(Resource<Option<T>>);
+
+impl<T: Send + Sync + 'static> Clone for RouteData<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for RouteData<T> {}
+
+/// The data loaded by the `loader` of the current route or one of its parents, as a
+/// [`Resource`] to read inside `<Suspense>` or `<Transition>`.
+///
+/// Resolves to [`None`] when the URL matched by the router doesn't parse into the route,
+/// e.g. `/user/abc` for `/user/:id` with a numeric `id`, as the loader can't be called then.
+///
+/// # Panics
+///
+/// Panics outside a route whose loader returns `T`.
+pub fn use_route_data<T>() -> Resource<Option<T>>
+where
+    T: Send + Sync + 'static,
+{
+    use_context::<RouteData<T>>()
+        .expect("use_route_data::<T>() called outside a route with a loader returning T")
+        .0
+}
+
+/// `url` relative to `base`, the path matched by the closest parent route, as its nested
+/// enum parses it. [`None`] if `url` is not below `base`.
+fn strip_route_base(base: &str, url: &str) -> Option<String> {
+    let rest = url.strip_prefix(base.trim_end_matches('/'))?;
+    match rest {
+        "" => Some("/".to_owned()),
+        rest if rest.starts_with(['?', '#']) => Some(format!("/{rest}")),
+        rest if rest.starts_with('/') => Some(rest.to_owned()),
+        _ => None,
+    }
+}
+
+/// The instance of a variant that `url` parses into. While the URL already points at another
+/// variant, whose view replaces this one, the previous instance is kept; URLs that don't parse
+/// at all resolve to [`None`].
+fn parse_variant_route<R: FromStr + Clone>(
+    base: &str,
+    url: &str,
+    is_variant: fn(&R) -> bool,
+    prev: Option<&Option<R>>,
+) -> Option<R> {
+    let route = R::from_str(&strip_route_base(base, url)?).ok()?;
+    if is_variant(&route) {
+        Some(route)
+    } else {
+        prev.cloned().flatten()
+    }
+}
+
+/// The current instance of the `is_variant` route, for views of that route. Parent routes
+/// (`nested`) only re-parse when their own matched path changes, not on child navigation.
+pub(crate) fn use_variant_route<R>(is_variant: fn(&R) -> bool, nested: bool) -> Memo<Option<R>>
+where
+    R: FromStr + Clone + PartialEq + Send + Sync + 'static,
+{
+    let base = use_context::<RouteBase>();
+    let url = use_current_url();
+    let matched = nested.then(use_matched);
+    Memo::new(move |prev: Option<&Option<R>>| {
+        let base = base.map(|base| base.0.get_untracked()).unwrap_or_default();
+        let parse = |url: &String| parse_variant_route(&base, url, is_variant, prev);
+        match matched {
+            Some(matched) => {
+                matched.track();
+                url.with_untracked(parse)
+            }
+            None => url.with(parse),
+        }
+    })
+}
+
+/// Starts `loader` with the current route and provides its result to `view` through
+/// [`use_route_data`]. This is what `loader = ...` route attributes expand to.
+///
+/// The loader re-runs whenever the route's `is_variant` instance changes; for `nested`
+/// (parent) routes, only when the parent's own path does, not on every child navigation.
+/// Loaders start as the route renders, so a parent's and its children's run side by side,
+/// and are serialized from the server like any other [`Resource`]. A fresh
+/// [`prefetch`](crate::prelude::prefetch) of the current URL is reused instead.
+pub fn with_route_data<R, T, L, Fut, F, V>(
+    is_variant: fn(&R) -> bool,
+    nested: bool,
+    loader: L,
+    view: F,
+) -> impl IntoView
+where
+    R: FromStr + Clone + PartialEq + Send + Sync + 'static,
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    L: Fn(R) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = T> + Send + 'static,
+    F: FnOnce() -> V,
+    V: IntoView,
+{
+    let route = use_variant_route(is_variant, nested);
+    let url = use_current_url();
+    let data = Resource::new(
+        move || route.get(),
+        move |route| {
+            // Prefetches are cached under the full URL, not the possibly nested `route`
+            let load = route.map(|route| match url.with_untracked(|url| prefetched::<R, T>(url)) {
+                Some(data) => data.boxed(),
+                None => loader(route).boxed(),
+            });
+            async move {
+                match load {
+                    Some(load) => Some(load.await),
+                    None => None,
+                }
+            }
+        },
+    );
+    provide_context(RouteData(data));
+    view()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Page {
+        Home,
+        Item(u32),
+    }
+
+    impl FromStr for Page {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, ()> {
+            match s.split(['?', '#']).next().unwrap_or_default() {
+                "/" => Ok(Page::Home),
+                path => path.strip_prefix("/item/").ok_or(())?.parse().map(Page::Item).map_err(drop),
+            }
+        }
+    }
+
+    fn is_item(page: &Page) -> bool {
+        matches!(page, Page::Item(_))
+    }
+
+    #[test]
+    fn test_strip_route_base() {
+        assert_eq!(strip_route_base("", "/item/1").as_deref(), Some("/item/1"));
+        assert_eq!(strip_route_base("/t/acme", "/t/acme/item/1").as_deref(), Some("/item/1"));
+        assert_eq!(strip_route_base("/t/acme/", "/t/acme/item/1").as_deref(), Some("/item/1"));
+        assert_eq!(strip_route_base("/t/acme", "/t/acme").as_deref(), Some("/"));
+        assert_eq!(strip_route_base("/t/acme", "/t/acme?x=1").as_deref(), Some("/?x=1"));
+        assert_eq!(strip_route_base("/t/acme", "/t/acme#top").as_deref(), Some("/#top"));
+        assert_eq!(strip_route_base("/t/acme", "/t/acmecorp/item/1"), None);
+        assert_eq!(strip_route_base("/t/acme", "/t/globex/item/1"), None);
+    }
+
+    #[test]
+    fn test_parse_variant_route() {
+        assert_eq!(parse_variant_route("/t/acme", "/t/acme/item/2", is_item, None), Some(Page::Item(2)));
+        // Another variant keeps the previous instance while this view is replaced
+        let prev = Some(Page::Item(2));
+        assert_eq!(parse_variant_route("/t/acme", "/t/acme", is_item, Some(&prev)), prev);
+        assert_eq!(parse_variant_route("", "/", is_item, None), None);
+        // A URL that doesn't parse never reuses the previous instance
+        assert_eq!(parse_variant_route("/t/acme", "/t/acme/item/abc", is_item, Some(&prev)), None);
+        assert_eq!(parse_variant_route("/t/acme", "/t/globex/item/2", is_item, Some(&prev)), None);
+    }
+}
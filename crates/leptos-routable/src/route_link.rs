@@ -0,0 +1,132 @@
+use crate::combine_paths::combine_paths;
+use crate::prefetch::{on_visible, Prefetch};
+use crate::route_data::{use_relative_route, use_route_base};
+use crate::Routable;
+use leptos::prelude::*;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// How [`RouteLink`] decides whether the current route makes it active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RouteMatch {
+    /// The current route equals the link's route, params and query included.
+    Exact,
+    /// The current route is within the link's route. A link to a nested index route
+    /// (`path = "/"`) covers its whole parent, e.g. every `/admin/...` route.
+    Prefix,
+    /// The current route is the same variant chain, ignoring params and query.
+    #[default]
+    Variant,
+}
+
+impl RouteMatch {
+    /// Returns `true` if `current` should mark a link to `target` as active.
+    pub fn is_active<R>(self, target: &R, current: &R) -> bool
+    where
+        R: Routable + PartialEq,
+    {
+        match self {
+            Self::Exact => target == current,
+            Self::Variant => target.variant_chain() == current.variant_chain(),
+            Self::Prefix => current.variant_chain().starts_with(&section_chain(target)),
+        }
+    }
+}
+
+/// `route`'s variant chain, without a trailing nested index variant.
+fn section_chain<R: Routable>(route: &R) -> Vec<&'static str> {
+    let mut chain = route.variant_chain();
+    let mut table = R::ROUTE_TABLE;
+    let mut last_path = None;
+    for variant in &chain {
+        let Some(info) = table.iter().find(|info| info.variant == *variant) else {
+            last_path = None;
+            break;
+        };
+        last_path = Some(info.path);
+        table = info.nested;
+    }
+    if chain.len() > 1 && matches!(last_path, Some("" | "/")) {
+        chain.pop();
+    }
+    chain
+}
+
+/// A link whose active state is decided by comparing route enums instead of URL strings.
+///
+/// Sets `aria-current="page"` and appends `active_class` while active.
+/// A nested enum's route is linked and compared relative to the parent route it is rendered
+/// under, e.g. `AdminRoutes::Users` inside `/admin` links to `/admin/users`.
+/// With `prefetch`, the route's loaders and lazy views are warmed before it is followed.
+#[component]
+pub fn RouteLink<R>(
+    /// The route to link to.
+    route: R,
+    /// Classes always applied to the `<a>`.
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// Classes appended while the link is active.
+    #[prop(optional, into)]
+    active_class: Option<String>,
+    /// How the current route is compared with `route`. Written `r#match=` inside `view!`.
+    #[prop(optional)]
+    r#match: RouteMatch,
+    /// When the route is prefetched, see [`Routable::prefetch`].
+    #[prop(optional)]
+    prefetch: Prefetch,
+    children: Children,
+) -> impl IntoView
+where
+    R: Routable + Display + FromStr + Clone + PartialEq + Send + Sync + 'static,
+{
+    let current = use_relative_route::<R>();
+    let base = use_route_base();
+    let path = route.to_string();
+    let href = move || combine_paths(&base.get(), &path);
+    let target = StoredValue::new(route.clone());
+    let warm = move || {
+        if prefetch == Prefetch::Hover {
+            target.with_value(Routable::prefetch);
+        }
+    };
+    let anchor = NodeRef::<leptos::html::A>::new();
+    if prefetch == Prefetch::Visible {
+        Effect::new(move |_| {
+            if let Some(anchor) = anchor.get() {
+                on_visible(&anchor, move || target.with_value(Routable::prefetch));
+            }
+        });
+    }
+    let is_active = Memo::new(move |_| {
+        current.with(|current| {
+            current
+                .as_ref()
+                .is_some_and(|current| r#match.is_active(&route, current))
+        })
+    });
+
+    let class = move || {
+        let mut classes = class.clone().unwrap_or_default();
+        if let (true, Some(active)) = (is_active.get(), active_class.as_ref()) {
+            if !classes.is_empty() {
+                classes.push(' ');
+            }
+            classes.push_str(active);
+        }
+        classes
+    };
+
+    view! {
+        <a
+            node_ref=anchor
+            href=href
+            class=class
+            aria-current=move || is_active.get().then_some("page")
+            on:mouseenter=move |_| warm()
+            on:focus=move |_| warm()
+            on:touchstart=move |_| warm()
+        >
+            {children()}
+        </a>
+    }
+}
@@ -0,0 +1,48 @@
+use crate::encoding::{decode_query_component, encode_query_component};
+use crate::parse_error::RouteParseError;
+use std::collections::HashMap;
+
+/// Query pairs keyed by decoded key. Values are kept percent-encoded so that
+/// `#[query(separator = ...)]` fields can be split before decoding.
+pub type RawQueryParams = HashMap<String, Vec<String>>;
+
+/// A reusable group of query parameters, usually produced by `#[derive(RouteQuery)]`
+/// and embedded in route variants with `#[query(flatten)]`.
+pub trait RouteQuery: Sized {
+    /// Appends `(key, encoded value)` pairs for every present field.
+    fn append_query(&self, pairs: &mut Vec<(String, String)>);
+
+    /// Builds the struct from raw query params.
+    fn from_query(params: &RawQueryParams) -> Result<Self, RouteParseError>;
+
+    /// Renders the query string without a leading `?`, sorted by key.
+    fn to_query_string(&self) -> String {
+        let mut pairs = Vec::new();
+        self.append_query(&mut pairs);
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        pairs
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", encode_query_component(&k), v))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Parses a query string, with or without a leading `?`.
+    fn from_query_string(query: &str) -> Result<Self, RouteParseError> {
+        Self::from_query(&parse_query_string(query))
+    }
+}
+
+/// Splits a query string into [`RawQueryParams`], preserving repeated keys in order.
+pub fn parse_query_string(query: &str) -> RawQueryParams {
+    let mut params = RawQueryParams::new();
+    for pair in query.trim_start_matches('?').split('&') {
+        if let Some((key, value)) = pair.split_once('=') {
+            // Keys that don't decode can't name a field
+            if let Ok(key) = decode_query_component(key) {
+                params.entry(key).or_default().push(value.to_string());
+            }
+        }
+    }
+    params
+}
@@ -0,0 +1,100 @@
+use crate::combine_paths::combine_paths;
+use crate::sitemap::{RobotsOptions, SitemapOptions};
+use std::ops::Deref;
+
+/// Which route attribute declared a [`RouteInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteKind {
+    /// `#[route]`
+    Route,
+    /// `#[parent_route]`, or a nested enum without a path of its own.
+    Parent,
+    /// `#[protected_route]`
+    Protected,
+    /// `#[protected_parent_route]`
+    ProtectedParent,
+}
+
+impl RouteKind {
+    /// Whether routes of this kind mount a nested enum.
+    pub fn is_parent(self) -> bool {
+        matches!(self, Self::Parent | Self::ProtectedParent)
+    }
+
+    pub fn is_protected(self) -> bool {
+        matches!(self, Self::Protected | Self::ProtectedParent)
+    }
+}
+
+/// A `:param`, `:param?` or `*wildcard` segment of a route path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParamInfo {
+    pub name: &'static str,
+    /// The field type as written on the variant, e.g. `"u64"` or `"Vec<String>"`.
+    pub ty: &'static str,
+}
+
+/// Compile-time description of one variant, as listed in [`Routable::ROUTE_TABLE`](crate::Routable::ROUTE_TABLE).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RouteInfo {
+    pub variant: &'static str,
+    /// The path pattern declared on the variant, relative to its parent route.
+    /// [`RouteEntry::path`] is the full pattern.
+    pub path: &'static str,
+    /// The variant's `aliases`, relative to its parent like `path`.
+    pub aliases: &'static [&'static str],
+    /// `(locale, path)` pairs of the variant's `path_<locale>` arguments.
+    pub localized: &'static [(&'static str, &'static str)],
+    pub kind: RouteKind,
+    pub params: &'static [ParamInfo],
+    /// Query keys of the variant's query fields. Fields using `#[query(flatten)]` are not expanded.
+    pub query: &'static [&'static str],
+    pub fallback: bool,
+    /// The variant's own `sitemap(...)` options, see [`SitemapOptions::for_route`].
+    pub sitemap: SitemapOptions,
+    /// The variant's own `robots(...)` options.
+    pub robots: RobotsOptions,
+    /// The `ROUTE_TABLE` of the nested enum for parent routes, empty otherwise.
+    pub nested: &'static [RouteInfo],
+}
+
+/// A [`RouteInfo`] resolved against its parents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteEntry {
+    /// The full path pattern, e.g. `/dashboard/settings`.
+    pub path: String,
+    /// Variant names from the root enum down to this entry.
+    pub variant_chain: Vec<&'static str>,
+    pub info: &'static RouteInfo,
+}
+
+impl Deref for RouteEntry {
+    type Target = RouteInfo;
+
+    fn deref(&self) -> &RouteInfo {
+        self.info
+    }
+}
+
+/// Flattens a route table depth-first, parents before their nested routes, combining
+/// each nested path with its parent's via [`combine_paths`].
+pub fn route_entries(table: &'static [RouteInfo]) -> Vec<RouteEntry> {
+    let mut entries = Vec::new();
+    collect_entries(table, "", &[], &mut entries);
+    entries
+}
+
+fn collect_entries(
+    table: &'static [RouteInfo],
+    prefix: &str,
+    chain: &[&'static str],
+    entries: &mut Vec<RouteEntry>,
+) {
+    for info in table {
+        let path = combine_paths(prefix, info.path);
+        let mut variant_chain = chain.to_vec();
+        variant_chain.push(info.variant);
+        entries.push(RouteEntry { path: path.clone(), variant_chain: variant_chain.clone(), info });
+        collect_entries(info.nested, &path, &variant_chain, entries);
+    }
+}
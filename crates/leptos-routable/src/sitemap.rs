@@ -0,0 +1,219 @@
+//! `sitemap.xml` and `robots.txt` rendering from a route enum's [`ROUTE_TABLE`](crate::Routable::ROUTE_TABLE).
+//!
+//! Per-variant options come from `#[route(sitemap(priority = 0.8, changefreq = "weekly", exclude))]`.
+//! Nested routes inherit `exclude` from their parents and fall back to the parent's
+//! `priority`/`changefreq` when they set none. Guarded and protected routes are left out
+//! unless they declare `sitemap(include)`.
+//!
+//! `robots.txt` only disallows routes declared with `#[route(robots(disallow))]`; leaving a
+//! route out of the sitemap doesn't hide it from crawlers.
+
+use crate::Routable;
+use std::collections::HashSet;
+use std::fmt::{Display, Write};
+
+/// The `<changefreq>` of a sitemap URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeFreq {
+    Always,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+    Never,
+}
+
+impl ChangeFreq {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::Hourly => "hourly",
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+            Self::Yearly => "yearly",
+            Self::Never => "never",
+        }
+    }
+}
+
+/// The `sitemap(...)` options of a route.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SitemapOptions {
+    pub priority: Option<f32>,
+    pub changefreq: Option<ChangeFreq>,
+    /// Set by `sitemap(exclude)`, and for guarded or protected routes without `sitemap(include)`.
+    pub exclude: bool,
+}
+
+/// The `robots(...)` options of a route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RobotsOptions {
+    /// Set by `robots(disallow)`, see [`render_robots_txt`].
+    pub disallow: bool,
+}
+
+impl SitemapOptions {
+    /// Options of `route`, merged down its variant chain. Fallback routes are always excluded.
+    pub fn for_route<R: Routable>(route: &R) -> Self {
+        let mut options = Self::default();
+        let mut table = R::ROUTE_TABLE;
+        for variant in route.variant_chain() {
+            let Some(info) = table.iter().find(|info| info.variant == variant) else {
+                break;
+            };
+            options.priority = info.sitemap.priority.or(options.priority);
+            options.changefreq = info.sitemap.changefreq.or(options.changefreq);
+            options.exclude |= info.sitemap.exclude || info.fallback;
+            table = info.nested;
+        }
+        options
+    }
+}
+
+/// Sitemap generation for a route enum.
+///
+/// Parameter-less routes are listed automatically; implement [`Sitemap::sitemap_entries`]
+/// to add instances of routes with parameters:
+///
+/// ```ignore
+/// impl Sitemap for AppRoutes {
+///     fn sitemap_entries() -> Vec<Self> {
+///         load_asset_ids().into_iter().map(|id| AppRoutes::AssetDetails { id, action: None }).collect()
+///     }
+/// }
+///
+/// let xml = AppRoutes::sitemap_xml("https://example.com");
+/// ```
+pub trait Sitemap: Routable + Display + Sized {
+    /// Routes with parameters to list alongside [`Routable::all_static_routes`].
+    fn sitemap_entries() -> Vec<Self> {
+        Vec::new()
+    }
+
+    /// Renders `sitemap.xml` with every URL prefixed by `base_url`.
+    fn sitemap_xml(base_url: &str) -> String {
+        render_sitemap(base_url, Self::all_static_routes().chain(Self::sitemap_entries()))
+    }
+
+    /// Renders `robots.txt`, disallowing `robots(disallow)` routes and pointing at `{base_url}/sitemap.xml`.
+    fn robots_txt(base_url: &str) -> String {
+        render_robots_txt::<Self>(base_url)
+    }
+}
+
+/// Renders a `sitemap.xml` document for `routes`, skipping excluded routes and duplicate URLs.
+pub fn render_sitemap<R>(base_url: &str, routes: impl IntoIterator<Item = R>) -> String
+where
+    R: Routable + Display,
+{
+    let base_url = base_url.trim_end_matches('/');
+    let mut seen = HashSet::new();
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+
+    for route in routes {
+        let options = SitemapOptions::for_route(&route);
+        if options.exclude {
+            continue;
+        }
+        let href = route.to_string();
+        let href = href.split('#').next().unwrap_or_default();
+        let loc = format!("{base_url}{href}");
+        if !seen.insert(loc.clone()) {
+            continue;
+        }
+
+        let _ = writeln!(xml, "  <url>\n    <loc>{}</loc>", escape_xml(&loc));
+        if let Some(changefreq) = options.changefreq {
+            let _ = writeln!(xml, "    <changefreq>{}</changefreq>", changefreq.as_str());
+        }
+        if let Some(priority) = options.priority {
+            // `Debug` keeps the declared digits, `0.85` stays `0.85` and `1.0` stays `1.0`
+            let _ = writeln!(xml, "    <priority>{priority:?}</priority>");
+        }
+        xml.push_str("  </url>\n");
+    }
+
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// Renders a `robots.txt` that disallows every route declared with `robots(disallow)`.
+///
+/// Rules are anchored with `$` so `/admin` doesn't also disallow `/administrators`; parent
+/// routes additionally disallow everything below `/admin/`, covering their nested routes.
+/// Path parameters become `*` wildcards.
+pub fn render_robots_txt<R: Routable>(base_url: &str) -> String {
+    let mut robots = String::from("User-agent: *\n");
+    let mut disallowed = Vec::new();
+    let mut disallowed_parents: Vec<&[&'static str]> = Vec::new();
+    for entry in R::route_entries() {
+        // Already covered by a disallowed parent's subtree rule
+        if disallowed_parents.iter().any(|parent| entry.variant_chain.starts_with(parent)) {
+            continue;
+        }
+        if !entry.info.robots.disallow {
+            continue;
+        }
+        for pattern in robots_patterns(&entry.path) {
+            disallowed.push(format!("{pattern}$"));
+            if entry.info.kind.is_parent() {
+                disallowed.push(format!("{}/", pattern.trim_end_matches('/')));
+            }
+        }
+        if entry.info.kind.is_parent() {
+            disallowed_parents.push(&entry.variant_chain);
+        }
+    }
+
+    if disallowed.is_empty() {
+        robots.push_str("Disallow:\n");
+    }
+    for path in disallowed {
+        let _ = writeln!(robots, "Disallow: {path}");
+    }
+
+    let _ = writeln!(robots, "\nSitemap: {}/sitemap.xml", base_url.trim_end_matches('/'));
+    robots
+}
+
+/// The robots.txt patterns matching `path`: `:param` and `*wildcard` segments become `*`,
+/// and a path with `:param?` segments yields one pattern with and one without each of them.
+fn robots_patterns(path: &str) -> Vec<String> {
+    let mut patterns = vec![String::new()];
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        let optional = segment.starts_with(':') && segment.ends_with('?');
+        let segment = if segment.starts_with([':', '*']) { "*" } else { segment };
+        let with_segment: Vec<_> = patterns.iter().map(|pattern| format!("{pattern}/{segment}")).collect();
+        if optional {
+            patterns.extend(with_segment);
+        } else {
+            patterns = with_segment;
+        }
+    }
+    for pattern in &mut patterns {
+        if pattern.is_empty() {
+            pattern.push('/');
+        }
+    }
+    patterns
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
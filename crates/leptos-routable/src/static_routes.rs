@@ -0,0 +1,49 @@
+use leptos_router::static_routes::StaticParamsMap;
+
+/// Collects the prerender params of the route `pattern` from concrete hrefs of that route,
+/// as produced by a `static_params` function. Hrefs that don't fit the pattern are skipped.
+///
+/// Leptos prerenders every combination of the collected values, so routes with several
+/// params may render combinations that none of the hrefs produced.
+pub fn static_params_map(pattern: &str, hrefs: impl IntoIterator<Item = String>) -> StaticParamsMap {
+    let pattern: Vec<_> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let mut params = StaticParamsMap::new();
+
+    for href in hrefs {
+        let path = href.split(['?', '#']).next().unwrap_or_default();
+        let segments: Vec<_> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let Some(values) = match_pattern(&pattern, &segments) else {
+            continue;
+        };
+        for (key, value) in values {
+            let mut existing = params.get(key).cloned().unwrap_or_default();
+            if !existing.contains(&value) {
+                existing.push(value);
+                params.insert(key, existing);
+            }
+        }
+    }
+    params
+}
+
+fn match_pattern<'a>(pattern: &[&'a str], segments: &[&str]) -> Option<Vec<(&'a str, String)>> {
+    let mut values = Vec::new();
+    for (i, part) in pattern.iter().enumerate() {
+        if let Some(name) = part.strip_prefix('*') {
+            values.push((name, segments.get(i..)?.join("/")));
+            return Some(values);
+        }
+        let segment = segments.get(i);
+        match part.strip_prefix(':') {
+            Some(name) => match (name.strip_suffix('?'), segment) {
+                (Some(name), Some(value)) => values.push((name, value.to_string())),
+                (Some(_), None) => {}
+                (None, Some(value)) => values.push((name, value.to_string())),
+                (None, None) => return None,
+            },
+            None if segment != Some(part) => return None,
+            None => {}
+        }
+    }
+    (segments.len() <= pattern.len()).then_some(values)
+}
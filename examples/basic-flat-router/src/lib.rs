@@ -4,28 +4,29 @@ use leptos_routable::prelude::*;
 use leptos_router::hooks::{use_location};
 use leptos_router::components::{Router, A};
 
-#[derive(Routable, PartialEq, Debug)]
+#[derive(Routable, PartialEq, Debug, Clone)]
 #[routes(
     view_prefix = "",
     view_suffix = "View",
-    transition = false
+    transition = false,
+    inject_title
 )]
 pub enum AppRoutes {
-    #[route(path = "/")]
+    #[route(path = "/", title = "Home")]
     Home,
-    #[route(path = "/contact")]
+    #[route(path = "/contact", title = "Contact", description = "Get in touch with us")]
     Contact,
-    #[route(path = "/asset")]
+    #[route(path = "/asset", title = "Assets")]
     AssetList,
-    #[route(path = "/asset/:id")]
+    #[route(path = "/asset/:id", title = "Asset {id}", meta(robots = "noindex"))]
     AssetDetails {
         id: u64,
         action: Option<String>,
     },
-    #[route(path = "/profile")]
+    #[route(path = "/profile", title = "Profile")]
     Profile,
     #[fallback]
-    #[route(path = "/404")]
+    #[route(path = "/404", title = "Not Found")]
     NotFound,
 }
 
@@ -51,6 +52,8 @@ pub fn ContactView() -> impl IntoView {
 
 #[component]
 pub fn AssetListView() -> impl IntoView {
+    let current_route = use_current_route::<AppRoutes>();
+
     view! {
         <div class="p-4">
             <h1 class="text-2xl font-bold mb-4">"Asset List"</h1>
@@ -113,6 +116,9 @@ pub fn AssetListView() -> impl IntoView {
                     <p class="text-sm font-mono">
                         "Query String: " {move || use_location().search.get()}
                     </p>
+                    <p class="text-sm font-mono">
+                        "Current Route: " {move || format!("{:?}", current_route.get())}
+                    </p>
                 </div>
             </div>
         </div>
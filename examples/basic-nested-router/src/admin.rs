@@ -1,25 +1,45 @@
 use leptos::prelude::*;
-use leptos_routable::prelude::Routable;
+use leptos_routable::prelude::{Breadcrumbs, GuardOutcome, Routable, RouteGuard};
 use leptos_router::components::{Outlet, A};
-use crate::AppRoutes;
+use crate::{AppRoutes, AuthContext};
 
-#[derive(Routable)]
+#[derive(Routable, Clone, PartialEq)]
 #[routes(
     view_prefix = "",
     view_suffix = "View",
-    transition = false
+    transition = false,
+    guard = RequireAdminLogin
 )]
 pub enum AdminRoutes {
     #[route(path = "/")]
     AdminHome,
 
-    #[route(path = "/users")]
+    #[route(path = "/users", label = "Users")]
     UserList,
 
     #[fallback]
     AdminNotFound,
 }
 
+/// Checks the login again for every admin page, as guards on `AdminRoutes` see (and can
+/// only redirect to) admin routes, asking to log in in place of the page.
+pub struct RequireAdminLogin;
+
+impl RouteGuard for RequireAdminLogin {
+    type Route = AdminRoutes;
+
+    fn check(_route: &AdminRoutes) -> impl std::future::Future<Output = GuardOutcome<AdminRoutes>> + Send {
+        let logged_in = expect_context::<AuthContext>().is_logged_in.get_untracked();
+        async move {
+            if logged_in {
+                GuardOutcome::Allow
+            } else {
+                GuardOutcome::render(|| view! { <p class="p-4 text-gray-500">"Log in to manage the site."</p> })
+            }
+        }
+    }
+}
+
 #[component]
 pub fn AdminHomeView() -> impl IntoView {
     view! {
@@ -57,5 +77,8 @@ pub fn AdminNotFoundView() -> impl IntoView {
 
 #[component]
 pub fn AdminView() -> impl IntoView {
-    view! { <Outlet /> }
+    view! {
+        <Breadcrumbs<AppRoutes> class="flex gap-2 p-4 text-sm text-gray-600" />
+        <Outlet />
+    }
 }
@@ -4,16 +4,17 @@ use leptos_routable::prelude::*;
 use leptos_router::components::Outlet;
 use crate::AppRoutes;
 
-#[derive(Routable)]
+#[derive(Routable, Clone, PartialEq)]
 #[routes(transition = false)]
+#[allow(clippy::enum_variant_names)]
 pub enum DashboardRoutes {
     #[route(path = "")]
     DashboardHome,
 
-    #[route(path = "/settings")]
+    #[route(path = "/settings", label = "Settings")]
     DashboardSettings,
 
-    #[route(path = "/analytics")]
+    #[route(path = "/analytics", label = "Analytics")]
     DashboardAnalytics,
 
     #[fallback]
@@ -94,6 +95,9 @@ pub fn DashboardNotFoundView() -> impl IntoView {
 
 #[component]
 pub fn DashboardView() -> impl IntoView {
-    view! { <Outlet /> }
+    view! {
+        <Breadcrumbs<AppRoutes> class="flex gap-2 p-4 text-sm text-gray-600" />
+        <Outlet />
+    }
 }
 
@@ -2,7 +2,10 @@ mod dashboard;
 mod admin;
 use leptos::prelude::*;
 use leptos_meta::{Html, Meta, Title};
-use leptos_routable::prelude::{MaybeParam, Routable};
+use leptos_routable::prelude::{
+    use_route_data, use_typed_navigator, GuardOutcome, MaybeParam, Prefetch, ReturnTo, Routable,
+    RouteGuard, RouteLink, RouteMatch,
+};
 use leptos_router::components::{Router, A};
 use crate::dashboard::{DashboardRoutes, DashboardView};
 use crate::admin::{AdminRoutes, AdminView};
@@ -21,27 +24,56 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
 }
 
 fn get_auth_redirect_path() -> AppRoutes {
-    AppRoutes::Login
+    AppRoutes::Login { return_to: None }
 }
 
 fn get_auth_condition() -> Option<bool> {
     Some(expect_context::<AuthContext>().is_logged_in.get())
 }
 
-#[derive(Routable)]
+/// Sends visitors who aren't logged in to the login page.
+pub struct RequireLogin;
+
+impl RouteGuard for RequireLogin {
+    type Route = AppRoutes;
+
+    fn check(_route: &AppRoutes) -> impl std::future::Future<Output = GuardOutcome<AppRoutes>> + Send {
+        let logged_in = expect_context::<AuthContext>().is_logged_in.get_untracked();
+        async move {
+            if logged_in {
+                GuardOutcome::Allow
+            } else {
+                GuardOutcome::Redirect(AppRoutes::Login { return_to: None })
+            }
+        }
+    }
+
+    fn pending() -> impl IntoView {
+        view! { <p class="p-4 text-gray-500">"Checking access..."</p> }
+    }
+}
+
+#[derive(Routable, Clone, PartialEq)]
 #[routes(
     view_prefix = "",
     view_suffix = "View",
     transition = false
 )]
+#[redirect(from = "/home", to = Home)]
 pub enum AppRoutes {
     #[route(path = "/")]
     Home,
     #[route(path = "/contact")]
     Contact,
-    #[route(path = "/asset")]
+    #[route(path = "/assets", aliases = ["/asset"])]
     AssetList,
-    #[route(path = "/asset/:id")]
+    #[route(
+        path = "/assets/:id",
+        aliases = ["/asset/:id"],
+        ssr = "::leptos_router::SsrMode::Async",
+        guard = RequireLogin,
+        loader = "load_asset"
+    )]
     AssetDetails {
         id: u64,
         action: Option<String>,
@@ -50,11 +82,15 @@ pub enum AppRoutes {
         path = "/profile",
         condition = "get_auth_condition",
         redirect_path = "get_auth_redirect_path",
-        fallback = "NotFoundView"
+        fallback = "NotFoundView",
+        ssr = "::leptos_router::SsrMode::InOrder"
     )]
     Profile,
     #[route(path = "/login")]
-    Login,
+    Login {
+        #[query(return_to)]
+        return_to: Option<ReturnTo<AppRoutes>>,
+    },
     #[parent_route(
         path = "/dashboard",
         ssr = "::leptos_router::SsrMode::default()"
@@ -65,7 +101,9 @@ pub enum AppRoutes {
         condition = "get_auth_condition",
         redirect_path = "get_auth_redirect_path",
         fallback = "NotFoundView",
-        ssr = "::leptos_router::SsrMode::default()"
+        ssr = "::leptos_router::SsrMode::default()",
+        lazy,
+        loading = "AdminLoadingView"
     )]
     Admin(AdminRoutes),
     #[fallback]
@@ -73,13 +111,19 @@ pub enum AppRoutes {
     NotFound,
 }
 
+/// Shown while the admin section's wasm chunk loads.
+#[component]
+pub fn AdminLoadingView() -> impl IntoView {
+    view! { <p class="p-4 text-gray-500">"Loading admin..."</p> }
+}
+
 #[component]
 pub fn LoginView() -> impl IntoView {
     let auth = expect_context::<AuthContext>();
-    let navigate = leptos_router::hooks::use_navigate();
+    let navigate = use_typed_navigator::<AppRoutes>();
     let login = Callback::<()>::new(move |_| {
         auth.set_logged_in.set(true);
-        navigate(&*AppRoutes::Profile.to_string(), Default::default());
+        navigate.return_or(AppRoutes::Profile);
     });
     let logout = Callback::<()>::new(move |_| {
         auth.set_logged_in.set(false);
@@ -168,15 +212,16 @@ pub fn AssetListView() -> impl IntoView {
                     >
                         "→ Contact Page"
                     </A>
-                    <A
-                        href=AppRoutes::AssetDetails {
+                    <RouteLink
+                        route=AppRoutes::AssetDetails {
                             id: 123,
                             action: None,
                         }
-                        attr:class="inline-block px-4 py-2 bg-blue-500 text-white rounded"
+                        class="inline-block px-4 py-2 bg-blue-500 text-white rounded"
+                        prefetch=Prefetch::Hover
                     >
-                        "→ Asset 123 (no action)"
-                    </A>
+                        "→ Asset 123 (no action, prefetched on hover)"
+                    </RouteLink>
                     <A
                         href=AppRoutes::AssetDetails {
                             id: 456,
@@ -227,8 +272,18 @@ pub fn AssetListView() -> impl IntoView {
     }
 }
 
+/// Loads the asset shown by [`AssetDetailsView`], with the route already parsed.
+async fn load_asset(route: AppRoutes) -> String {
+    match route {
+        AppRoutes::AssetDetails { id, action: Some(action) } => format!("Asset #{id} ({action})"),
+        AppRoutes::AssetDetails { id, .. } => format!("Asset #{id}"),
+        _ => String::new(),
+    }
+}
+
 #[component]
 pub fn AssetDetailsView() -> impl IntoView {
+    let asset = use_route_data::<String>();
     let id = MaybeParam::<u64>::new("id").ok();
     let prev_href = move || {
         AppRoutes::AssetDetails {
@@ -249,6 +304,13 @@ pub fn AssetDetailsView() -> impl IntoView {
             <h1 class="text-2xl font-bold">
                 {move || format!("Asset ID: {}", id.get().unwrap_or_default())}
             </h1>
+            <Suspense fallback=|| view! { <p class="text-gray-500">"Loading asset..."</p> }>
+                <p>
+                    {move || Suspend::new(async move {
+                        asset.await.unwrap_or_else(|| "Unknown asset".to_string())
+                    })}
+                </p>
+            </Suspense>
             <div class="flex space-x-4">
                 <A href=AppRoutes::Home attr:class="px-4 py-2 bg-green-500 text-white rounded">
                     "Home"
@@ -334,18 +396,21 @@ pub fn App() -> impl IntoView {
                     >
                         "Profile"
                     </A>
-                    <A
-                        href=AppRoutes::Dashboard(DashboardRoutes::DashboardHome)
-                        attr:class="text-white px-3 py-1 bg-blue-600 rounded"
+                    <RouteLink
+                        route=AppRoutes::Dashboard(DashboardRoutes::DashboardHome)
+                        class="text-white px-3 py-1 bg-blue-600 rounded"
+                        active_class="ring-2 ring-white"
                     >
                         "Dashboard"
-                    </A>
-                    <A
-                        href=AppRoutes::Admin(AdminRoutes::AdminHome)
-                        attr:class="text-white px-3 py-1 bg-blue-600 rounded"
+                    </RouteLink>
+                    <RouteLink
+                        route=AppRoutes::Admin(AdminRoutes::AdminHome)
+                        class="text-white px-3 py-1 bg-blue-600 rounded"
+                        active_class="ring-2 ring-white"
+                        r#match=RouteMatch::Prefix
                     >
                         "Admin"
-                    </A>
+                    </RouteLink>
                 </nav>
                 <AuthProvider>{move || AppRoutes::routes()}</AuthProvider>
             </Router>
@@ -0,0 +1,90 @@
+//! Path `aliases` and enum-level `#[redirect(...)]` routes.
+use leptos::prelude::*;
+use leptos_routable::prelude::Routable;
+use leptos_router::components::Outlet;
+
+#[derive(Routable, PartialEq, Debug)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+#[redirect(from = "/home", to = Home)]
+#[redirect(from = "/users/me", to = "AliasRoutes::User { id: 0 }")]
+pub enum AliasRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/user/:id")]
+    User { id: u64 },
+
+    #[route(path = "/post/:id", aliases = ["/p/:id", "/article/:id"])]
+    Post { id: u64, comment: Option<String> },
+
+    #[parent_route(path = "/blog")]
+    Blog(AliasBlogRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Debug)]
+#[routes(view_prefix = "Blog", view_suffix = "View", transition = false)]
+#[redirect(from = "/latest", to = "AliasBlogRoutes::Entry { slug: \"hello\".to_string() }")]
+#[redirect(from = "/old", to = Index)]
+pub enum AliasBlogRoutes {
+    #[route(path = "/")]
+    Index,
+
+    #[route(path = "/entry/:slug")]
+    Entry { slug: String },
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn HomeView() -> &'static str { "home" }
+fn UserView() -> &'static str { "user" }
+fn PostView() -> &'static str { "post" }
+fn BlogView() -> impl IntoView { view! { <Outlet/> } }
+fn NotFoundView() -> &'static str { "notfound" }
+fn BlogIndexView() -> &'static str { "blog_index" }
+fn BlogEntryView() -> &'static str { "blog_entry" }
+fn BlogNotFoundView() -> &'static str { "blog_notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ssr::render;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_aliases_parse_to_canonical_route() {
+        let expected = AliasRoutes::Post { id: 7, comment: Some("hi".to_string()) };
+        assert_eq!(AliasRoutes::from_str("/p/7?comment=hi").unwrap(), expected);
+        assert_eq!(AliasRoutes::from_str("/article/7?comment=hi").unwrap(), expected);
+        assert_eq!(AliasRoutes::from_str("/post/7?comment=hi").unwrap(), expected);
+        assert_eq!(expected.to_string(), "/post/7?comment=hi");
+        assert!(AliasRoutes::from_str("/p/seven").is_err());
+    }
+
+    #[test]
+    fn test_route_table_aliases() {
+        let info = |variant| AliasRoutes::ROUTE_TABLE.iter().find(|info| info.variant == variant).unwrap();
+        assert_eq!(info("Post").aliases, &["/p/:id", "/article/:id"]);
+        assert!(info("Home").aliases.is_empty());
+        // Redirect sources are routes only, never parsed into a variant
+        assert!(AliasRoutes::from_str("/home").is_err());
+    }
+
+    #[test]
+    fn test_redirects_render_their_target() {
+        assert_eq!(render::<AliasRoutes>("/home").1.as_deref(), Some("/"));
+        assert_eq!(render::<AliasRoutes>("/users/me").1.as_deref(), Some("/user/0"));
+    }
+
+    #[test]
+    fn test_nested_redirects_resolve_against_the_parent_route() {
+        assert_eq!(render::<AliasRoutes>("/blog/latest").1.as_deref(), Some("/blog/entry/hello"));
+        assert_eq!(render::<AliasRoutes>("/blog/old").1.as_deref(), Some("/blog"));
+        assert_eq!(render::<AliasRoutes>("/blog/entry/hello").1, None);
+    }
+}
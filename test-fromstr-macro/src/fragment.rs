@@ -0,0 +1,54 @@
+//! `#[fragment]` fields rendered as, and parsed from, the URL `#anchor`.
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum FragmentRoutes {
+    #[route(path = "/post/:id")]
+    Post { id: u64, comment: Option<String> },
+
+    #[route(path = "/docs/:page")]
+    Docs {
+        page: String,
+        version: Option<u32>,
+        #[fragment]
+        section: Option<String>,
+    },
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn PostView() -> &'static str { "post" }
+fn DocsView() -> &'static str { "docs" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_fragment_display_and_parse() {
+        let route = FragmentRoutes::Docs {
+            page: "intro".to_string(),
+            version: Some(2),
+            section: Some("getting started".to_string()),
+        };
+        assert_eq!(route.to_string(), "/docs/intro?version=2#getting%20started");
+        assert_eq!(FragmentRoutes::from_str(&route.to_string()).unwrap(), route);
+    }
+
+    #[test]
+    fn test_fragment_does_not_leak_into_query() {
+        let route = FragmentRoutes::from_str("/post/1?comment=hi#section").unwrap();
+        assert_eq!(route, FragmentRoutes::Post { id: 1, comment: Some("hi".to_string()) });
+    }
+
+    #[test]
+    fn test_fragment_empty_is_none() {
+        let route = FragmentRoutes::from_str("/docs/intro#").unwrap();
+        assert_eq!(route, FragmentRoutes::Docs { page: "intro".to_string(), version: None, section: None });
+    }
+}
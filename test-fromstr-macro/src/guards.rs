@@ -0,0 +1,242 @@
+//! Route guards on variants and whole nested enums, run before the route renders.
+use crate::loaders::{load_admin_users, loaded};
+use leptos::prelude::*;
+use leptos_routable::prelude::{GuardOutcome, RouteGuard, Routable};
+use leptos_router::components::Outlet;
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum GuardRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/post/:id", guard = AllowAll)]
+    Post { id: u64 },
+
+    #[route(path = "/files/*path", guard = PrivateFiles)]
+    Files { path: String },
+
+    #[parent_route(path = "/admin")]
+    Admin(GuardAdminRoutes),
+
+    #[parent_route(path = "/staff")]
+    Staff(GuardAdminRoutes),
+
+    #[route(path = "/premium", guard = Paywall)]
+    Premium,
+
+    #[route(path = "/stalled", guard = NeverDecides)]
+    Stalled,
+
+    #[parent_route(path = "/archive", guard = ArchivedUsersOnly)]
+    Archive(GuardAdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(
+    view_prefix = "Admin",
+    view_suffix = "View",
+    transition = false,
+    guard = BlockDrafts
+)]
+pub enum GuardAdminRoutes {
+    #[route(path = "/users", loader = "load_admin_users")]
+    Users,
+
+    #[route(path = "/drafts")]
+    Drafts,
+
+    #[fallback]
+    #[route(path = "/404", guard = none)]
+    NotFound,
+}
+
+pub struct AllowAll;
+
+impl RouteGuard for AllowAll {
+    type Route = GuardRoutes;
+
+    async fn check(_route: &GuardRoutes) -> GuardOutcome<GuardRoutes> {
+        GuardOutcome::Allow
+    }
+}
+
+/// Keeps `/files/private/...` from rendering.
+pub struct PrivateFiles;
+
+impl RouteGuard for PrivateFiles {
+    type Route = GuardRoutes;
+
+    async fn check(route: &GuardRoutes) -> GuardOutcome<GuardRoutes> {
+        match route {
+            GuardRoutes::Files { path } if path.starts_with("private/") => GuardOutcome::Redirect(GuardRoutes::Home),
+            _ => GuardOutcome::Allow,
+        }
+    }
+}
+
+/// Sends `/drafts` back to the user list of whichever parent the admin routes are under.
+pub struct BlockDrafts;
+
+impl RouteGuard for BlockDrafts {
+    type Route = GuardAdminRoutes;
+
+    async fn check(route: &GuardAdminRoutes) -> GuardOutcome<GuardAdminRoutes> {
+        match route {
+            GuardAdminRoutes::Drafts => GuardOutcome::Redirect(GuardAdminRoutes::Users),
+            _ => GuardOutcome::Allow,
+        }
+    }
+}
+
+/// Shows a paywall in place of `/premium`.
+pub struct Paywall;
+
+impl RouteGuard for Paywall {
+    type Route = GuardRoutes;
+
+    async fn check(_route: &GuardRoutes) -> GuardOutcome<GuardRoutes> {
+        GuardOutcome::render(|| "paywall")
+    }
+}
+
+/// Keeps `/stalled` checking forever.
+pub struct NeverDecides;
+
+impl RouteGuard for NeverDecides {
+    type Route = GuardRoutes;
+
+    async fn check(_route: &GuardRoutes) -> GuardOutcome<GuardRoutes> {
+        std::future::pending().await
+    }
+
+    fn pending() -> impl IntoView {
+        "checking"
+    }
+}
+
+/// Only lets the user list through under `/archive`.
+pub struct ArchivedUsersOnly;
+
+impl RouteGuard for ArchivedUsersOnly {
+    type Route = GuardRoutes;
+
+    async fn check(route: &GuardRoutes) -> GuardOutcome<GuardRoutes> {
+        match route {
+            GuardRoutes::Archive(GuardAdminRoutes::Users) => GuardOutcome::Allow,
+            _ => GuardOutcome::Redirect(GuardRoutes::Home),
+        }
+    }
+}
+
+fn HomeView() -> &'static str { "home" }
+fn PostView() -> &'static str { "post" }
+fn FilesView() -> &'static str { "files" }
+fn AdminView() -> impl IntoView { view! { "admin " <Outlet/> } }
+fn StaffView() -> impl IntoView { view! { "staff " <Outlet/> } }
+fn PremiumView() -> &'static str { "premium_content" }
+fn StalledView() -> &'static str { "stalled_content" }
+fn ArchiveView() -> impl IntoView { view! { "archive " <Outlet/> } }
+fn NotFoundView() -> &'static str { "notfound" }
+fn AdminUsersView() -> impl IntoView { loaded::<Vec<String>>(|users| users.join(", ")) }
+fn AdminDraftsView() -> &'static str { "admin_drafts" }
+fn AdminNotFoundView() -> &'static str { "admin_notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ssr::{render, render_pending};
+    use futures::executor::block_on;
+    use leptos_routable::prelude::{AllOf, AnyOf};
+
+    #[test]
+    fn test_route_guard_check() {
+        let private = GuardRoutes::Files { path: "private/keys".to_string() };
+        let public = GuardRoutes::Files { path: "public/readme".to_string() };
+        assert!(matches!(
+            block_on(PrivateFiles::check(&private)),
+            GuardOutcome::Redirect(GuardRoutes::Home)
+        ));
+        assert!(matches!(block_on(PrivateFiles::check(&public)), GuardOutcome::Allow));
+    }
+
+    #[test]
+    fn test_route_guard_combinators() {
+        let private = GuardRoutes::Files { path: "private/keys".to_string() };
+        assert!(matches!(
+            block_on(AllOf::<AllowAll, PrivateFiles>::check(&private)),
+            GuardOutcome::Redirect(GuardRoutes::Home)
+        ));
+        assert!(matches!(block_on(AnyOf::<PrivateFiles, AllowAll>::check(&private)), GuardOutcome::Allow));
+        assert!(matches!(
+            block_on(AnyOf::<PrivateFiles, PrivateFiles>::check(&private)),
+            GuardOutcome::Redirect(GuardRoutes::Home)
+        ));
+    }
+
+    #[test]
+    fn test_guards_run_on_the_server() {
+        let (public, redirect) = render::<GuardRoutes>("/files/public/readme");
+        assert!(public.contains("files"), "{public}");
+        assert_eq!(redirect, None);
+
+        let (private, redirect) = render::<GuardRoutes>("/files/private/keys");
+        assert!(!private.contains("files"), "{private}");
+        assert_eq!(redirect.as_deref(), Some("/"));
+
+        // Enum-level guards cover the nested routes, which still load their data
+        let (admin, _) = render::<GuardRoutes>("/admin/users");
+        assert!(admin.contains("alice, bob"), "{admin}");
+    }
+
+    #[test]
+    fn test_nested_guard_checks_its_own_enum_under_each_parent() {
+        for parent in ["/admin", "/staff"] {
+            let (users, redirect) = render::<GuardRoutes>(&format!("{parent}/users"));
+            assert!(users.contains("alice, bob"), "{users}");
+            assert_eq!(redirect, None);
+
+            let (drafts, redirect) = render::<GuardRoutes>(&format!("{parent}/drafts"));
+            assert!(!drafts.contains("admin_drafts"), "{drafts}");
+            assert_eq!(redirect, Some(format!("{parent}/users")));
+        }
+    }
+
+    #[test]
+    fn test_guard_renders_fallback_for_unparsed_url() {
+        assert!(render::<GuardRoutes>("/post/1").0.contains("post"));
+        // `/post/abc` matches `/post/:id` in the router, but the guard never sees a `GuardRoutes::Post`
+        let (html, redirect) = render::<GuardRoutes>("/post/abc");
+        assert!(html.contains("notfound") && !html.contains("post"), "{html}");
+        assert_eq!(redirect, None);
+    }
+
+    #[test]
+    fn test_guard_renders_its_own_view() {
+        let (html, redirect) = render::<GuardRoutes>("/premium");
+        assert!(html.contains("paywall") && !html.contains("premium_content"), "{html}");
+        assert_eq!(redirect, None);
+    }
+
+    #[test]
+    fn test_guard_shows_pending_until_checked() {
+        let pending = render_pending::<GuardRoutes>("/stalled");
+        assert!(pending.contains("checking") && !pending.contains("stalled_content"), "{pending}");
+    }
+
+    #[test]
+    fn test_parent_route_guard_covers_nested_routes() {
+        let (users, redirect) = render::<GuardRoutes>("/archive/users");
+        assert!(users.contains("archive") && users.contains("alice, bob"), "{users}");
+        assert_eq!(redirect, None);
+
+        // Refused by the parent's guard before the nested enum's own guard runs
+        let (settings, redirect) = render::<GuardRoutes>("/archive/drafts");
+        assert!(!settings.contains("archive"), "{settings}");
+        assert_eq!(redirect.as_deref(), Some("/"));
+    }
+}
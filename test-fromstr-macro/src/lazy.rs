@@ -0,0 +1,63 @@
+//! `lazy` route views with their `loading` fallbacks.
+use leptos::prelude::*;
+use leptos_routable::prelude::Routable;
+use leptos_router::components::Outlet;
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "Lazy", view_suffix = "View", transition = false, loading = "LazyLoadingView")]
+pub enum LazyRoutes {
+    #[route(path = "/", lazy, loading = "|| \"loading home\"")]
+    Home,
+
+    #[parent_route(path = "/t/:tenant", lazy)]
+    Tenant {
+        tenant: String,
+        #[nested]
+        inner: LazyInnerRoutes,
+    },
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "LazyInner", view_suffix = "View", transition = false)]
+pub enum LazyInnerRoutes {
+    #[route(path = "/users", lazy)]
+    Users,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn LazyHomeView() -> &'static str { "home" }
+fn LazyTenantView() -> impl IntoView { view! { "tenant " <Outlet/> } }
+fn LazyLoadingView() -> &'static str { "loading" }
+fn LazyNotFoundView() -> &'static str { "notfound" }
+fn LazyInnerUsersView() -> &'static str { "users" }
+fn LazyInnerNotFoundView() -> &'static str { "inner_notfound" }
+
+#[cfg(test)]
+mod tests {
+    use crate::ssr::{first_chunk, render};
+    use leptos::prelude::*;
+    use leptos_routable::prelude::lazy_view;
+
+    #[test]
+    fn test_lazy_views_render_at_once_on_the_server() {
+        let (home, _) = render::<super::LazyRoutes>("/");
+        assert!(home.contains("home") && !home.contains("loading"), "{home}");
+        let (tenant, _) = render::<super::LazyRoutes>("/t/acme/users");
+        assert!(tenant.contains("tenant") && tenant.contains("users") && !tenant.contains("loading"), "{tenant}");
+    }
+
+    #[test]
+    fn test_lazy_view_shows_loading_until_its_chunk_resolves() {
+        // Server builds resolve lazy routes at once, so stand in for a chunk still being fetched
+        let load = || std::future::pending::<AnyView>();
+        let html = first_chunk("/", || lazy_view(|| "loading", load));
+        assert!(html.contains("loading"), "{html}");
+    }
+}
@@ -1,5 +1,25 @@
+#![allow(non_snake_case)]
 use leptos_routable::prelude::Routable;
-use std::str::FromStr;
+
+// One fixture enum per feature, each module next to the tests using it
+pub mod aliases;
+pub mod fragment;
+pub mod guards;
+pub mod lazy;
+pub mod loaders;
+pub mod localized;
+pub mod metadata;
+pub mod nested_params;
+pub mod parse_error;
+pub mod paths;
+pub mod query;
+pub mod route_link;
+pub mod return_to;
+pub mod route_table;
+pub mod sitemap;
+#[cfg(test)]
+mod ssr;
+pub mod static_routes;
 
 #[derive(Routable, PartialEq, Debug)]
 #[routes(view_prefix = "", view_suffix = "View", transition = false)]
@@ -27,7 +47,7 @@ pub enum TestRoutes {
     NotFound,
 }
 
-#[derive(Routable, PartialEq, Debug)]
+#[derive(Routable, PartialEq, Debug, Clone)]
 #[routes(view_prefix = "", view_suffix = "View", transition = false)]
 pub enum AdminRoutes {
     #[route(path = "/users")]
@@ -55,6 +75,7 @@ fn NotFoundView() -> &'static str { "notfound" }
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     #[test]
     fn test_from_str_home() {
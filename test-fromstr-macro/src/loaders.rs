@@ -0,0 +1,102 @@
+//! Route `loader`s, their data in views through `use_route_data`, and `prefetch`.
+use leptos::prelude::*;
+use leptos_routable::prelude::{use_route_data, Routable};
+use leptos_router::components::Outlet;
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum LoaderRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/about")]
+    About,
+
+    #[route(path = "/user/:id", loader = "load_user")]
+    User { id: u64 },
+
+    #[parent_route(path = "/admin")]
+    Admin(LoaderAdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "Admin", view_suffix = "View", transition = false)]
+pub enum LoaderAdminRoutes {
+    #[route(path = "/users", loader = "load_admin_users")]
+    Users,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+/// Ids `load_user` was called with, as soon as it is called rather than polled.
+static LOADED_USERS: std::sync::Mutex<Vec<u64>> = std::sync::Mutex::new(Vec::new());
+
+fn load_user(route: LoaderRoutes) -> impl std::future::Future<Output = String> {
+    let id = match route {
+        LoaderRoutes::User { id } => Some(id),
+        _ => None,
+    };
+    LOADED_USERS.lock().unwrap().extend(id);
+    async move { id.map(|id| format!("user {id}")).unwrap_or_default() }
+}
+
+pub(crate) async fn load_admin_users<R>(_route: R) -> Vec<String> {
+    vec!["alice".to_string(), "bob".to_string()]
+}
+
+/// Renders the route's loaded data, or `no data` for URLs the loader couldn't run for.
+pub(crate) fn loaded<T>(render: fn(T) -> String) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let data = use_route_data::<T>();
+    view! {
+        <Suspense>
+            {move || Suspend::new(async move { data.await.map_or_else(|| "no data".to_string(), render) })}
+        </Suspense>
+    }
+}
+
+fn HomeView() -> &'static str { "home" }
+fn AboutView() -> &'static str { "about" }
+fn UserView() -> impl IntoView { loaded::<String>(|user| user) }
+fn AdminView() -> impl IntoView { view! { "admin " <Outlet/> } }
+fn NotFoundView() -> &'static str { "notfound" }
+fn AdminUsersView() -> impl IntoView { loaded::<Vec<String>>(|users| users.join(", ")) }
+fn AdminNotFoundView() -> &'static str { "admin_notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ssr::render;
+
+    #[test]
+    fn test_prefetch_outside_browser_is_noop() {
+        leptos_routable::prelude::prefetch(LoaderRoutes::User { id: 7 });
+        LoaderRoutes::Admin(LoaderAdminRoutes::Users).prefetch();
+        LoaderRoutes::About.prefetch();
+        // Loaders never start, and rendering the route loads it afresh
+        assert!(!LOADED_USERS.lock().unwrap().contains(&7));
+        assert!(render::<LoaderRoutes>("/user/7").0.contains("user 7"));
+        assert!(LOADED_USERS.lock().unwrap().contains(&7));
+    }
+
+    #[test]
+    fn test_loaders_run_on_the_server() {
+        assert!(render::<LoaderRoutes>("/user/1").0.contains("user 1"));
+        let (admin, _) = render::<LoaderRoutes>("/admin/users");
+        assert!(admin.contains("alice, bob"), "{admin}");
+    }
+
+    #[test]
+    fn test_loader_resolves_without_data_for_unparsed_url() {
+        // `/user/abc` matches `/user/:id` in the router, but never parses into `LoaderRoutes::User`
+        assert!(render::<LoaderRoutes>("/user/abc").0.contains("no data"));
+    }
+}
@@ -0,0 +1,162 @@
+//! Enum-level `locales` with per-locale `path_<locale>` paths, including through nested enums.
+use leptos::prelude::*;
+use leptos_routable::prelude::Routable;
+use leptos_router::components::Outlet;
+
+#[derive(Routable, PartialEq, Debug)]
+#[routes(view_prefix = "Localized", view_suffix = "View", transition = false, locales = ["en", "de", "fr"])]
+pub enum LocalizedRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/settings", path_de = "/einstellungen", path_fr = "/parametres")]
+    Settings,
+
+    #[route(path = "/user/:id", path_de = "/benutzer/:id")]
+    User { id: u64, tab: Option<String> },
+
+    #[route(path = "/about", path_de = "/über-uns")]
+    About,
+
+    #[parent_route(path = "/admin", path_de = "/verwaltung")]
+    Admin(LocalizedAdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Debug)]
+#[routes(view_prefix = "LocalizedAdmin", view_suffix = "View", transition = false)]
+pub enum LocalizedAdminRoutes {
+    #[route(path = "/users", path_de = "/benutzer")]
+    Users,
+
+    #[route(path = "/settings")]
+    Settings,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn LocalizedHomeView() -> &'static str { "home" }
+fn LocalizedSettingsView() -> &'static str { "settings" }
+fn LocalizedUserView() -> &'static str { "user" }
+fn LocalizedAboutView() -> &'static str { "about" }
+fn LocalizedAdminView() -> impl IntoView { view! { "admin " <Outlet/> } }
+fn LocalizedNotFoundView() -> &'static str { "notfound" }
+fn LocalizedAdminUsersView() -> &'static str { "admin_users" }
+fn LocalizedAdminSettingsView() -> &'static str { "admin_settings" }
+fn LocalizedAdminNotFoundView() -> &'static str { "admin_notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ssr::render;
+    use leptos_routable::prelude::RouteParseError;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_to_href_in_locale() {
+        assert_eq!(LocalizedRoutes::Settings.to_href_in("de"), "/de/einstellungen");
+        assert_eq!(LocalizedRoutes::Settings.to_href_in("fr"), "/fr/parametres");
+        assert_eq!(LocalizedRoutes::Settings.to_href_in("en"), "/en/settings");
+        assert_eq!(LocalizedRoutes::Settings.to_href_in("it"), "/settings");
+        assert_eq!(LocalizedRoutes::Settings.to_string(), "/settings");
+        assert_eq!(LocalizedRoutes::Home.to_href_in("de"), "/de");
+
+        let user = LocalizedRoutes::User { id: 3, tab: Some("posts".to_string()) };
+        assert_eq!(user.to_href_in("de"), "/de/benutzer/3?tab=posts");
+        assert_eq!(user.to_href_in("fr"), "/fr/user/3?tab=posts");
+
+        let admin = LocalizedRoutes::Admin(LocalizedAdminRoutes::Users);
+        assert_eq!(admin.to_href_in("de"), "/de/verwaltung/benutzer");
+        assert_eq!(admin.to_href_in("en"), "/en/admin/users");
+    }
+
+    #[test]
+    fn test_from_str_localized_paths() {
+        for input in ["/settings", "/en/settings", "/de/einstellungen", "/fr/parametres"] {
+            assert_eq!(LocalizedRoutes::from_str(input).unwrap(), LocalizedRoutes::Settings, "{input}");
+        }
+        assert_eq!(LocalizedRoutes::from_str("/de").unwrap(), LocalizedRoutes::Home);
+        assert_eq!(
+            LocalizedRoutes::from_str("/de/benutzer/3?tab=posts").unwrap(),
+            LocalizedRoutes::User { id: 3, tab: Some("posts".to_string()) }
+        );
+        assert_eq!(
+            LocalizedRoutes::from_str("/de/verwaltung/benutzer").unwrap(),
+            LocalizedRoutes::Admin(LocalizedAdminRoutes::Users)
+        );
+        assert_eq!(LocalizedAdminRoutes::from_str("/benutzer").unwrap(), LocalizedAdminRoutes::Users);
+    }
+
+    #[test]
+    fn test_from_str_rejects_mixed_locale_paths() {
+        // Only the paths registered with the router parse: one locale prefix, then that locale's paths
+        for input in ["/einstellungen", "/de/settings", "/en/einstellungen", "/de/en/fr/settings", "/fr/verwaltung/users"] {
+            assert!(LocalizedRoutes::from_str(input).is_err(), "{input}");
+        }
+        assert!(LocalizedRoutes::parse_localized("/de/en/settings").is_err());
+        // Nested enums without locales of their own follow the parent's locale prefix
+        for input in ["/en/admin/benutzer", "/fr/admin/benutzer", "/de/verwaltung/users"] {
+            assert!(LocalizedRoutes::from_str(input).is_err(), "{input}");
+        }
+        assert_eq!(
+            LocalizedRoutes::from_str("/en/admin/users").unwrap(),
+            LocalizedRoutes::Admin(LocalizedAdminRoutes::Users)
+        );
+        assert_eq!(
+            LocalizedRoutes::from_str("/fr/user/3").unwrap(),
+            LocalizedRoutes::User { id: 3, tab: None }
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_localized_path() {
+        assert_eq!(LocalizedRoutes::About.to_href_in("de"), "/de/über-uns");
+        // Browsers send the pathname percent-encoded
+        for input in ["/de/%C3%BCber-uns", "/de/%c3%bcber-uns", "/de/über-uns"] {
+            assert_eq!(LocalizedRoutes::from_str(input).unwrap(), LocalizedRoutes::About, "{input}");
+        }
+        assert!(LocalizedRoutes::from_str("/en/%C3%BCber-uns").is_err());
+        let (html, _) = render::<LocalizedRoutes>("/de/%C3%BCber-uns");
+        assert!(html.contains("about"), "{html}");
+    }
+
+    #[test]
+    fn test_router_registers_nested_paths_per_locale() {
+        for url in ["/de/verwaltung/benutzer", "/en/admin/users", "/admin/users"] {
+            let (html, _) = render::<LocalizedRoutes>(url);
+            assert!(html.contains("admin_users"), "{url}: {html}");
+        }
+        for url in ["/en/admin/benutzer", "/de/verwaltung/users"] {
+            let (html, _) = render::<LocalizedRoutes>(url);
+            assert!(!html.contains("admin_users") && html.contains("notfound"), "{url}: {html}");
+        }
+    }
+
+    #[test]
+    fn test_prefixed_parse_error_is_reported() {
+        assert!(matches!(
+            LocalizedRoutes::parse_localized("/de/benutzer/abc"),
+            Err(RouteParseError::ParamParse { variant: "LocalizedRoutes::User", field: "id", .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_localized_reports_locale() {
+        assert_eq!(
+            LocalizedRoutes::parse_localized("/de/einstellungen").unwrap(),
+            (LocalizedRoutes::Settings, Some("de"))
+        );
+        assert_eq!(LocalizedRoutes::parse_localized("/FR").unwrap(), (LocalizedRoutes::Home, Some("fr")));
+        assert_eq!(LocalizedRoutes::parse_localized("/settings").unwrap(), (LocalizedRoutes::Settings, None));
+        assert_eq!(LocalizedRoutes::LOCALES, &["en", "de", "fr"]);
+        assert!(LocalizedAdminRoutes::LOCALES.is_empty());
+
+        let settings = LocalizedRoutes::ROUTE_TABLE.iter().find(|info| info.variant == "Settings").unwrap();
+        assert_eq!(settings.localized, &[("de", "/einstellungen"), ("fr", "/parametres")]);
+    }
+}
@@ -0,0 +1,115 @@
+//! `title`, `description` and `meta(...)` route attributes, and breadcrumbs through nested enums.
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum MetaRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/about", title = "About", description = "About us", meta(robots = "noindex", author = "team"))]
+    About,
+
+    #[route(path = "/user/:id", title = "User {id}", meta("og:type" = "profile", "twitter:card" = "summary", robots = "noindex"))]
+    User { id: u64 },
+
+    #[parent_route(path = "/admin", title = "Admin", meta(robots = "noindex"))]
+    Admin(MetaAdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "Admin", view_suffix = "View", transition = false)]
+pub enum MetaAdminRoutes {
+    #[route(path = "/users", label = "Users")]
+    Users,
+
+    #[route(path = "/settings", title = "Admin Settings")]
+    Settings,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn HomeView() -> &'static str { "home" }
+fn AboutView() -> &'static str { "about" }
+fn UserView() -> &'static str { "user" }
+fn AdminView() -> &'static str { "admin" }
+fn NotFoundView() -> &'static str { "notfound" }
+fn AdminUsersView() -> &'static str { "admin_users" }
+fn AdminSettingsView() -> &'static str { "admin_settings" }
+fn AdminNotFoundView() -> &'static str { "admin_notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_routable::prelude::Breadcrumb;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_route_title() {
+        assert_eq!(MetaRoutes::About.title(), Some("About".to_string()));
+        assert_eq!(MetaRoutes::User { id: 7 }.title(), Some("User 7".to_string()));
+        assert_eq!(MetaRoutes::Home.title(), None);
+        assert_eq!(
+            MetaRoutes::Admin(MetaAdminRoutes::Settings).title(),
+            Some("Admin Settings".to_string())
+        );
+        assert_eq!(MetaRoutes::Admin(MetaAdminRoutes::Users).title(), Some("Admin".to_string()));
+    }
+
+    #[test]
+    fn test_route_title_does_not_affect_path() {
+        assert_eq!(MetaRoutes::User { id: 7 }.to_string(), "/user/7");
+        assert_eq!(MetaRoutes::from_str("/user/7").unwrap(), MetaRoutes::User { id: 7 });
+    }
+
+    #[test]
+    fn test_route_description_and_meta() {
+        assert_eq!(MetaRoutes::About.description(), Some("About us".to_string()));
+        assert_eq!(MetaRoutes::Home.description(), None);
+        assert_eq!(MetaRoutes::About.meta(), &[("author", "team"), ("robots", "noindex")]);
+        assert!(MetaRoutes::Home.meta().is_empty());
+        assert_eq!(MetaRoutes::Admin(MetaAdminRoutes::Users).meta(), &[("robots", "noindex")]);
+    }
+
+    #[test]
+    fn test_route_meta_string_keys() {
+        assert_eq!(
+            MetaRoutes::User { id: 7 }.meta(),
+            &[("og:type", "profile"), ("robots", "noindex"), ("twitter:card", "summary")]
+        );
+    }
+
+    #[test]
+    fn test_breadcrumbs_top_level() {
+        let crumbs = MetaRoutes::User { id: 7 }.breadcrumbs();
+        assert_eq!(crumbs.len(), 1);
+        assert_eq!(crumbs[0].label, "User");
+        assert_eq!(crumbs[0].href, "/user/7");
+        assert_eq!(crumbs[0].route, Some(MetaRoutes::User { id: 7 }));
+    }
+
+    #[test]
+    fn test_breadcrumbs_nested() {
+        let crumbs = MetaRoutes::Admin(MetaAdminRoutes::Users).breadcrumbs();
+        let levels: Vec<_> = crumbs.iter().map(|c| (c.label.as_str(), c.href.as_str())).collect();
+        assert_eq!(levels, vec![("Admin", "/admin"), ("Users", "/admin/users")]);
+        assert_eq!(crumbs[1].route, Some(MetaRoutes::Admin(MetaAdminRoutes::Users)));
+
+        let crumbs = MetaRoutes::Admin(MetaAdminRoutes::Settings).breadcrumbs();
+        assert_eq!(crumbs[1].label, "Settings");
+    }
+
+    #[test]
+    fn test_breadcrumb_nest_folds_index_route() {
+        let inner = vec![Breadcrumb::<MetaAdminRoutes>::new("Index", "/")];
+        let crumbs = Breadcrumb::<MetaRoutes>::nest("Admin".to_string(), "/admin".to_string(), inner);
+        assert_eq!(crumbs.len(), 1);
+        assert_eq!(crumbs[0].label, "Admin");
+    }
+}
@@ -0,0 +1,97 @@
+//! Parent routes with path params in front of their nested enum.
+use crate::loaders::{load_admin_users, loaded};
+use leptos::prelude::*;
+use leptos_routable::prelude::Routable;
+use leptos_router::components::Outlet;
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "Tenant", view_suffix = "View", transition = false)]
+pub enum TenantRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[parent_route(path = "/t/:tenant", title = "Tenant {tenant}", label = "{tenant}", loader = "load_tenant")]
+    Tenant {
+        tenant: String,
+        #[nested]
+        inner: TenantAdminRoutes,
+    },
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "TenantAdmin", view_suffix = "View", transition = false)]
+pub enum TenantAdminRoutes {
+    #[route(path = "/users", label = "Users", loader = "load_admin_users")]
+    Users,
+
+    #[route(path = "/settings", title = "Admin Settings")]
+    Settings,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+async fn load_tenant(route: TenantRoutes) -> String {
+    match route {
+        TenantRoutes::Tenant { tenant, .. } => tenant,
+        _ => String::new(),
+    }
+}
+
+fn TenantHomeView() -> &'static str { "home" }
+fn TenantTenantView() -> impl IntoView { view! { {loaded::<String>(|tenant| tenant)} " " <Outlet/> } }
+fn TenantNotFoundView() -> &'static str { "notfound" }
+fn TenantAdminUsersView() -> impl IntoView { loaded::<Vec<String>>(|users| users.join(", ")) }
+fn TenantAdminSettingsView() -> &'static str { "admin_settings" }
+fn TenantAdminNotFoundView() -> &'static str { "admin_notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ssr::render;
+    use leptos_routable::prelude::{ParamInfo, RouteKind, RouteParseError};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_parent_with_path_params_round_trip() {
+        let route = TenantRoutes::Tenant { tenant: "acme corp".to_string(), inner: TenantAdminRoutes::Settings };
+        assert_eq!(route.to_string(), "/t/acme%20corp/settings");
+        assert_eq!(TenantRoutes::from_str("/t/acme%20corp/settings").unwrap(), route);
+        assert_eq!(route.variant_chain(), vec!["Tenant", "Settings"]);
+        assert!(matches!(
+            TenantRoutes::from_str("/t/acme/nope"),
+            Err(RouteParseError::Nested { parent: "TenantRoutes::Tenant", .. })
+        ));
+    }
+
+    #[test]
+    fn test_parent_with_path_params_metadata() {
+        let users = TenantRoutes::Tenant { tenant: "acme".to_string(), inner: TenantAdminRoutes::Users };
+        assert_eq!(users.title(), Some("Tenant acme".to_string()));
+        let settings = TenantRoutes::Tenant { tenant: "acme".to_string(), inner: TenantAdminRoutes::Settings };
+        assert_eq!(settings.title(), Some("Admin Settings".to_string()));
+
+        let crumbs = users.breadcrumbs();
+        let crumbs: Vec<_> = crumbs.iter().map(|c| (c.label.as_str(), c.href.as_str())).collect();
+        assert_eq!(crumbs, vec![("acme", "/t/acme"), ("Users", "/t/acme/users")]);
+
+        let tenant = TenantRoutes::ROUTE_TABLE.iter().find(|info| info.variant == "Tenant").unwrap();
+        assert_eq!(tenant.kind, RouteKind::Parent);
+        assert_eq!(tenant.params, &[ParamInfo { name: "tenant", ty: "String" }]);
+        assert!(tenant.query.is_empty());
+        assert_eq!(tenant.nested, TenantAdminRoutes::ROUTE_TABLE);
+        assert_eq!(TenantRoutes::all_static_routes().collect::<Vec<_>>(), vec![TenantRoutes::Home]);
+    }
+
+    #[test]
+    fn test_parent_with_path_params_loads_data() {
+        TenantRoutes::Tenant { tenant: "acme".to_string(), inner: TenantAdminRoutes::Users }.prefetch();
+        let (tenant, _) = render::<TenantRoutes>("/t/acme/users");
+        assert!(tenant.contains("acme") && tenant.contains("alice, bob"), "{tenant}");
+    }
+}
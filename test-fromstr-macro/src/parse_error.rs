@@ -0,0 +1,141 @@
+//! `RouteParseError` as the error of the derived `FromStr`.
+use crate::AdminRoutes;
+use leptos_routable::prelude::Routable;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Routable, PartialEq, Debug)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum ErrorRoutes {
+    #[route(path = "/user/:id")]
+    User { id: u64 },
+
+    #[route(path = "/search/:term")]
+    Search { term: String, page: Option<u32> },
+
+    #[route(path = "/tag/:slug")]
+    Tag { slug: Slug },
+
+    #[route(path = "/item/:id")]
+    ItemById { id: u64 },
+
+    #[route(path = "/item/:slug")]
+    ItemBySlug { slug: Slug },
+
+    #[parent_route(path = "/admin")]
+    Admin(AdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+/// A lowercase ASCII slug, whose `FromStr` error isn't an `Error`.
+#[derive(PartialEq, Debug)]
+pub struct Slug(String);
+
+impl FromStr for Slug {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s.bytes().all(|b| b.is_ascii_lowercase() || b == b'-') {
+            true => Ok(Slug(s.to_string())),
+            false => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Slug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn UserView() -> &'static str { "user" }
+fn SearchView() -> &'static str { "search" }
+fn TagView() -> &'static str { "tag" }
+fn ItemByIdView() -> &'static str { "item_by_id" }
+fn ItemBySlugView() -> &'static str { "item_by_slug" }
+fn AdminView() -> &'static str { "admin" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_routable::prelude::RouteParseError;
+
+    #[test]
+    fn test_error_no_match() {
+        let err = ErrorRoutes::from_str("/unknown").unwrap_err();
+        assert!(matches!(err, RouteParseError::NoMatch { ref path } if path == "/unknown"));
+    }
+
+    #[test]
+    fn test_error_param_parse() {
+        let err = ErrorRoutes::from_str("/user/abc").unwrap_err();
+        match err {
+            RouteParseError::ParamParse { variant, field, raw, source } => {
+                assert_eq!(variant, "ErrorRoutes::User");
+                assert_eq!(field, "id");
+                assert_eq!(raw, "abc");
+                assert!(source.unwrap().downcast_ref::<std::num::ParseIntError>().is_some());
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_error_param_parse_without_error_source() {
+        assert_eq!(ErrorRoutes::from_str("/tag/rust-lang").unwrap(), ErrorRoutes::Tag { slug: Slug("rust-lang".to_string()) });
+        match ErrorRoutes::from_str("/tag/Rust").unwrap_err() {
+            RouteParseError::ParamParse { variant, field, raw, source } => {
+                assert_eq!(variant, "ErrorRoutes::Tag");
+                assert_eq!(field, "slug");
+                assert_eq!(raw, "Rust");
+                assert!(source.is_none());
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_failed_parse_falls_through_to_next_variant() {
+        assert_eq!(ErrorRoutes::from_str("/item/7").unwrap(), ErrorRoutes::ItemById { id: 7 });
+        assert_eq!(
+            ErrorRoutes::from_str("/item/rust").unwrap(),
+            ErrorRoutes::ItemBySlug { slug: Slug("rust".to_string()) }
+        );
+        // Nothing parses: the first variant's error is reported, and `From` falls back
+        assert!(matches!(
+            ErrorRoutes::from_str("/item/Rust"),
+            Err(RouteParseError::ParamParse { variant: "ErrorRoutes::ItemById", field: "id", .. })
+        ));
+        assert_eq!(ErrorRoutes::from("/item/Rust"), ErrorRoutes::NotFound);
+    }
+
+    #[test]
+    fn test_error_query_parse() {
+        let err = ErrorRoutes::from_str("/search/rust?page=two").unwrap_err();
+        match err {
+            RouteParseError::QueryParse { variant, field, raw, .. } => {
+                assert_eq!(variant, "ErrorRoutes::Search");
+                assert_eq!(field, "page");
+                assert_eq!(raw, "two");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_error_nested() {
+        let err = ErrorRoutes::from_str("/admin/unknown").unwrap_err();
+        match &err {
+            RouteParseError::Nested { parent, inner } => {
+                assert_eq!(*parent, "ErrorRoutes::Admin");
+                assert!(matches!(**inner, RouteParseError::NoMatch { .. }));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(matches!(err.root_cause(), RouteParseError::NoMatch { .. }));
+    }
+}
@@ -0,0 +1,145 @@
+//! Percent-encoded path params and query values, and `*wildcard` segments.
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum PathRoutes {
+    #[route(path = "/post/:id")]
+    Post { id: u64, comment: Option<String> },
+
+    #[route(path = "/search/:term")]
+    Search {
+        term: String,
+        filter: Option<String>,
+        page: Option<u32>,
+    },
+
+    #[route(path = "/files/*path")]
+    Files { path: String },
+
+    #[route(path = "/tags/*tags")]
+    Tags { tags: Vec<String> },
+
+    #[route(path = "/archive/:month?")]
+    Archive { month: Option<String> },
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn PostView() -> &'static str { "post" }
+fn SearchView() -> &'static str { "search" }
+fn FilesView() -> &'static str { "files" }
+fn TagsView() -> &'static str { "tags" }
+fn ArchiveView() -> &'static str { "archive" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_routable::prelude::{DecodeError, RouteParseError};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_display_encodes_path_param() {
+        let route = PathRoutes::Search { term: "a/b?c#d".to_string(), filter: None, page: None };
+        assert_eq!(route.to_string(), "/search/a%2Fb%3Fc%23d");
+    }
+
+    #[test]
+    fn test_display_encodes_query_value() {
+        let route = PathRoutes::Post { id: 1, comment: Some("x&y=z é".to_string()) };
+        assert_eq!(route.to_string(), "/post/1?comment=x%26y%3Dz%20%C3%A9");
+    }
+
+    #[test]
+    fn test_round_trip_arbitrary_strings() {
+        let raws = ["plain", "a b", "a/b", "?&=#", "100%", "%", "%2E", "naïve ☃", "a+b", "...", ".a"];
+        for raw in raws {
+            let route = PathRoutes::Search { term: raw.to_string(), filter: Some(raw.to_string()), page: None };
+            let parsed = PathRoutes::from_str(&route.to_string()).unwrap();
+            assert_eq!(parsed, route, "{route}");
+
+            let files = PathRoutes::Files { path: raw.to_string() };
+            assert_eq!(PathRoutes::from_str(&files.to_string()).unwrap(), files, "{files}");
+
+            let tags = PathRoutes::Tags { tags: vec![raw.to_string(), "x".to_string()] };
+            assert_eq!(PathRoutes::from_str(&tags.to_string()).unwrap(), tags, "{tags}");
+
+            let archive = PathRoutes::Archive { month: Some(raw.to_string()) };
+            assert_eq!(PathRoutes::from_str(&archive.to_string()).unwrap(), archive, "{archive}");
+        }
+    }
+
+    #[test]
+    fn test_empty_optional_segment_is_left_out() {
+        let archive = PathRoutes::Archive { month: Some(String::new()) };
+        assert_eq!(archive.to_string(), "/archive");
+        assert_eq!(PathRoutes::from_str("/archive").unwrap(), PathRoutes::Archive { month: None });
+    }
+
+    #[test]
+    fn test_empty_and_dot_segments_round_trip() {
+        for raw in ["", ".", ".."] {
+            let search = PathRoutes::Search { term: raw.to_string(), filter: None, page: None };
+            assert_eq!(PathRoutes::from_str(&search.to_string()).unwrap(), search, "{search}");
+            let tags = PathRoutes::Tags { tags: vec![raw.to_string(), "x".to_string()] };
+            assert_eq!(PathRoutes::from_str(&tags.to_string()).unwrap(), tags, "{tags}");
+        }
+        assert_eq!(PathRoutes::Search { term: "..".to_string(), filter: None, page: None }.to_string(), "/search/%..");
+        for path in ["a//b", "a/", "/", "a/../b", "./a"] {
+            let files = PathRoutes::Files { path: path.to_string() };
+            assert_eq!(PathRoutes::from_str(&files.to_string()).unwrap(), files, "{files}");
+        }
+        assert_eq!(PathRoutes::Files { path: "a//b".to_string() }.to_string(), "/files/a/%/b");
+        let archive = PathRoutes::Archive { month: Some("..".to_string()) };
+        assert_eq!(PathRoutes::from_str(&archive.to_string()).unwrap(), archive);
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_a_parse_error() {
+        match PathRoutes::from_str("/search/%FF?filter=ok").unwrap_err() {
+            RouteParseError::ParamParse { field, raw, source, .. } => {
+                assert_eq!(field, "term");
+                assert_eq!(raw, "%FF");
+                assert!(source.unwrap().is::<DecodeError>());
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(matches!(
+            PathRoutes::from_str("/search/ok?filter=%C3%28"),
+            Err(RouteParseError::QueryParse { field: "filter", .. })
+        ));
+        assert!(matches!(
+            PathRoutes::from_str("/files/a/%FF"),
+            Err(RouteParseError::ParamParse { field: "path", .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_str_decodes_plus_in_query() {
+        let route = PathRoutes::from_str("/post/7?comment=hello+world").unwrap();
+        assert_eq!(route, PathRoutes::Post { id: 7, comment: Some("hello world".to_string()) });
+    }
+
+    #[test]
+    fn test_wildcard_string_round_trip() {
+        let route = PathRoutes::Files { path: "docs/a b/readme.md".to_string() };
+        assert_eq!(route.to_string(), "/files/docs/a%20b/readme.md");
+        assert_eq!(PathRoutes::from_str(&route.to_string()).unwrap(), route);
+    }
+
+    #[test]
+    fn test_wildcard_matches_empty_rest() {
+        let route = PathRoutes::from_str("/files").unwrap();
+        assert_eq!(route, PathRoutes::Files { path: String::new() });
+    }
+
+    #[test]
+    fn test_wildcard_vec_round_trip() {
+        let route = PathRoutes::Tags { tags: vec!["rust".to_string(), "a/b".to_string()] };
+        assert_eq!(route.to_string(), "/tags/rust/a%2Fb");
+        assert_eq!(PathRoutes::from_str(&route.to_string()).unwrap(), route);
+    }
+}
@@ -0,0 +1,189 @@
+//! `#[query]` fields: required and renamed params, multi-valued params and `#[query(flatten)]`.
+use leptos_routable::prelude::{RouteQuery, Routable};
+use std::collections::HashSet;
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum QueryRoutes {
+    #[route(path = "/post/:id")]
+    Post { id: u64, comment: Option<String> },
+
+    #[route(path = "/find")]
+    Find {
+        #[query(rename = "q")]
+        term: String,
+        #[query]
+        limit: u32,
+        lang: Option<String>,
+    },
+
+    #[route(path = "/filter")]
+    Filter {
+        #[query(rename = "tag")]
+        tags: Vec<String>,
+        #[query(separator = ",")]
+        ids: Vec<u32>,
+        sizes: HashSet<String>,
+    },
+
+    #[route(path = "/items/:category")]
+    Items {
+        category: String,
+        #[query(flatten)]
+        paging: PageQuery,
+    },
+
+    #[parent_route(path = "/admin")]
+    Admin(QueryAdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "QueryAdmin", view_suffix = "View", transition = false)]
+pub enum QueryAdminRoutes {
+    #[route(path = "/")]
+    Index { q: Option<String> },
+
+    #[route(path = "/users")]
+    Users { q: Option<String> },
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(RouteQuery, PartialEq, Debug, Clone, Default)]
+pub struct PageQuery {
+    pub page: Option<u32>,
+    #[query(rename = "sort_by")]
+    pub sort: Option<String>,
+    #[query(separator = ",")]
+    pub filters: Vec<String>,
+}
+
+fn PostView() -> &'static str { "post" }
+fn FindView() -> &'static str { "find" }
+fn FilterView() -> &'static str { "filter" }
+fn ItemsView() -> &'static str { "items" }
+fn AdminView() -> &'static str { "admin" }
+fn NotFoundView() -> &'static str { "notfound" }
+fn QueryAdminIndexView() -> &'static str { "admin_index" }
+fn QueryAdminUsersView() -> &'static str { "admin_users" }
+fn QueryAdminNotFoundView() -> &'static str { "admin_notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_routable::prelude::RouteParseError;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_required_query_display_and_parse() {
+        let route = QueryRoutes::Find { term: "rust lang".to_string(), limit: 10, lang: None };
+        assert_eq!(route.to_string(), "/find?limit=10&q=rust%20lang");
+        assert_eq!(QueryRoutes::from_str(&route.to_string()).unwrap(), route);
+    }
+
+    #[test]
+    fn test_required_query_missing() {
+        let err = QueryRoutes::from_str("/find?limit=5").unwrap_err();
+        match err {
+            RouteParseError::MissingQuery { variant, field, key } => {
+                assert_eq!(variant, "QueryRoutes::Find");
+                assert_eq!(field, "term");
+                assert_eq!(key, "q");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_multi_valued_query_display() {
+        let route = QueryRoutes::Filter {
+            tags: vec!["b".to_string(), "a,c".to_string()],
+            ids: vec![3, 1],
+            sizes: HashSet::new(),
+        };
+        assert_eq!(route.to_string(), "/filter?ids=3,1&tag=b&tag=a%2Cc");
+    }
+
+    #[test]
+    fn test_multi_valued_query_round_trip() {
+        let route = QueryRoutes::Filter {
+            tags: vec!["x y".to_string(), "x y".to_string(), "z".to_string()],
+            ids: vec![],
+            sizes: ["s", "m"].into_iter().map(String::from).collect(),
+        };
+        assert_eq!(QueryRoutes::from_str(&route.to_string()).unwrap(), route);
+    }
+
+    #[test]
+    fn test_hash_set_query_display_is_sorted() {
+        let route = QueryRoutes::Filter {
+            tags: vec![],
+            ids: vec![],
+            sizes: ["xl", "s", "m", "l"].into_iter().map(String::from).collect(),
+        };
+        assert_eq!(route.to_string(), "/filter?sizes=l&sizes=m&sizes=s&sizes=xl");
+    }
+
+    #[test]
+    fn test_multi_valued_query_missing_is_empty() {
+        let route = QueryRoutes::from_str("/filter").unwrap();
+        assert_eq!(route, QueryRoutes::Filter { tags: vec![], ids: vec![], sizes: HashSet::new() });
+    }
+
+    #[test]
+    fn test_single_valued_query_keeps_last() {
+        let route = QueryRoutes::from_str("/post/1?comment=a&comment=b").unwrap();
+        assert_eq!(route, QueryRoutes::Post { id: 1, comment: Some("b".to_string()) });
+    }
+
+    #[test]
+    fn test_empty_optional_query_is_none() {
+        let route = QueryRoutes::from_str("/post/1?comment=").unwrap();
+        assert_eq!(route, QueryRoutes::Post { id: 1, comment: None });
+        assert_eq!(PageQuery::from_str("?page=&sort_by=").unwrap(), PageQuery::default());
+    }
+
+    #[test]
+    fn test_route_query_struct_round_trip() {
+        let query = PageQuery { page: Some(2), sort: Some("name".to_string()), filters: vec!["a".to_string(), "b".to_string()] };
+        assert_eq!(query.to_string(), "filters=a,b&page=2&sort_by=name");
+        assert_eq!(PageQuery::from_str(&format!("?{query}")).unwrap(), query);
+        assert_eq!(PageQuery::from_str("").unwrap(), PageQuery::default());
+    }
+
+    #[test]
+    fn test_flatten_query_display_and_parse() {
+        let route = QueryRoutes::Items {
+            category: "books".to_string(),
+            paging: PageQuery { page: Some(3), sort: None, filters: vec![] },
+        };
+        assert_eq!(route.to_string(), "/items/books?page=3");
+        assert_eq!(QueryRoutes::from_str(&route.to_string()).unwrap(), route);
+    }
+
+    #[test]
+    fn test_flatten_query_error() {
+        let err = QueryRoutes::from_str("/items/books?page=x").unwrap_err();
+        assert!(matches!(err, RouteParseError::QueryParse { variant: "PageQuery", field: "page", .. }));
+    }
+
+    #[test]
+    fn test_nested_index_query_keeps_path_shape() {
+        let index = |q: Option<&str>| QueryRoutes::Admin(QueryAdminRoutes::Index { q: q.map(String::from) });
+        assert_eq!(index(None).to_string(), "/admin");
+        assert_eq!(index(Some("x")).to_string(), "/admin?q=x");
+        for route in [index(None), index(Some("x"))] {
+            assert_eq!(QueryRoutes::from_str(&route.to_string()).unwrap(), route);
+        }
+
+        let users = QueryRoutes::Admin(QueryAdminRoutes::Users { q: Some("x".to_string()) });
+        assert_eq!(users.to_string(), "/admin/users?q=x");
+        assert_eq!(QueryRoutes::from_str("/admin/users?q=x").unwrap(), users);
+    }
+}
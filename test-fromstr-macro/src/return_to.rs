@@ -0,0 +1,116 @@
+//! `#[query(return_to)]` fields carrying the route a guard sent the user away from.
+use leptos_routable::prelude::{ReturnTo, Routable};
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum ReturnRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/about")]
+    About,
+
+    #[route(path = "/user/:id")]
+    User { id: u64 },
+
+    #[route(path = "/search/:term")]
+    Search { term: String, page: Option<u32> },
+
+    #[route(path = "/login")]
+    Login {
+        #[query(return_to)]
+        return_to: Option<ReturnTo<ReturnRoutes>>,
+    },
+
+    #[protected_route(path = "/billing", condition = "logged_out", redirect_path = "login", fallback = "NotFoundView")]
+    Billing,
+
+    #[protected_route(path = "/account", condition = "logged_out", redirect_path = "sign_in_help", fallback = "NotFoundView")]
+    Account,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn logged_out() -> Option<bool> {
+    Some(false)
+}
+
+fn login() -> ReturnRoutes {
+    ReturnRoutes::Login { return_to: None }
+}
+
+/// A `redirect_path` that is only `Display`, not a route.
+fn sign_in_help() -> std::borrow::Cow<'static, str> {
+    "/help/sign-in".into()
+}
+
+fn HomeView() -> &'static str { "home" }
+fn AboutView() -> &'static str { "about" }
+fn UserView() -> &'static str { "user" }
+fn SearchView() -> &'static str { "search" }
+fn LoginView() -> &'static str { "login" }
+fn BillingView() -> &'static str { "billing" }
+fn AccountView() -> &'static str { "account" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ssr::render;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_return_to_round_trip() {
+        let login = ReturnRoutes::Login {
+            return_to: Some(ReturnTo::new(ReturnRoutes::Search { term: "rust".to_string(), page: Some(2) })),
+        };
+        assert_eq!(login.to_string(), "/login?return_to=%2Fsearch%2Frust%3Fpage%3D2");
+        assert_eq!(ReturnRoutes::from_str(&login.to_string()).unwrap(), login);
+        assert_eq!(ReturnRoutes::from_str("/login").unwrap(), ReturnRoutes::Login { return_to: None });
+    }
+
+    #[test]
+    fn test_invalid_return_to_is_none() {
+        for input in ["/login?return_to=https%3A%2F%2Fevil.example", "/login?return_to=%2Fuser%2Fabc"] {
+            assert_eq!(ReturnRoutes::from_str(input).unwrap(), ReturnRoutes::Login { return_to: None }, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_protected_route_redirect_path() {
+        let (_, redirect) = render::<ReturnRoutes>("/billing");
+        assert_eq!(redirect.as_deref(), Some("/login?return_to=%2Fbilling"));
+        // Any `Display` works, and is redirected to as it is
+        let (_, redirect) = render::<ReturnRoutes>("/account");
+        assert_eq!(redirect.as_deref(), Some("/help/sign-in"));
+    }
+
+    #[test]
+    fn test_redirect_target_only_fills_declared_return_to() {
+        use leptos_routable::prelude::RedirectTarget;
+        assert_eq!(
+            ReturnRoutes::Login { return_to: None }.into_href("/user/7"),
+            "/login?return_to=%2Fuser%2F7"
+        );
+        assert_eq!(ReturnRoutes::About.into_href("/user/7"), "/about");
+        assert_eq!("/login".into_href("/user/7"), "/login");
+        assert_eq!("/login".to_string().into_href("/user/7"), "/login");
+    }
+
+    #[test]
+    fn test_set_return_to() {
+        let mut login = ReturnRoutes::Login { return_to: None };
+        login.set_return_to("/user/7");
+        assert_eq!(login, ReturnRoutes::Login { return_to: Some(ReturnTo::new(ReturnRoutes::User { id: 7 })) });
+
+        // An explicit target wins over the refused route
+        login.set_return_to("/about");
+        assert_eq!(login, ReturnRoutes::Login { return_to: Some(ReturnTo::new(ReturnRoutes::User { id: 7 })) });
+
+        let mut home = ReturnRoutes::Home;
+        home.set_return_to("/user/7");
+        assert_eq!(home, ReturnRoutes::Home);
+    }
+}
@@ -0,0 +1,109 @@
+//! Variant chains and the `RouteMatch` modes `RouteLink` compares routes with.
+use leptos::prelude::*;
+use leptos_routable::prelude::{RouteLink, Routable};
+use leptos_router::components::Outlet;
+
+#[derive(Routable, PartialEq, Debug)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum LinkRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/about")]
+    About,
+
+    #[route(path = "/post/:id")]
+    Post { id: u64, comment: Option<String> },
+
+    #[parent_route(path = "/admin")]
+    Admin(LinkAdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "LinkAdmin", view_suffix = "View", transition = false)]
+pub enum LinkAdminRoutes {
+    #[route(path = "/")]
+    Index,
+
+    #[route(path = "/users")]
+    Users,
+
+    #[route(path = "/settings")]
+    Settings,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn HomeView() -> &'static str { "home" }
+fn AboutView() -> &'static str { "about" }
+fn PostView() -> &'static str { "post" }
+fn AdminView() -> impl IntoView {
+    view! {
+        <RouteLink route=LinkAdminRoutes::Users active_class="active">"users"</RouteLink>
+        <RouteLink route=LinkAdminRoutes::Settings active_class="active">"settings"</RouteLink>
+        <Outlet/>
+    }
+}
+fn NotFoundView() -> &'static str { "notfound" }
+fn LinkAdminIndexView() -> &'static str { "admin_index" }
+fn LinkAdminUsersView() -> &'static str { "admin_users" }
+fn LinkAdminSettingsView() -> &'static str { "admin_settings" }
+fn LinkAdminNotFoundView() -> &'static str { "admin_notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ssr::render;
+    use leptos_routable::prelude::RouteMatch;
+
+    #[test]
+    fn test_variant_chain() {
+        assert_eq!(LinkRoutes::Home.variant_chain(), vec!["Home"]);
+        assert_eq!(LinkRoutes::Post { id: 1, comment: None }.variant_chain(), vec!["Post"]);
+        assert_eq!(
+            LinkRoutes::Admin(LinkAdminRoutes::Users).variant_chain(),
+            vec!["Admin", "Users"]
+        );
+    }
+
+    #[test]
+    fn test_route_match_modes() {
+        let post = |comment: Option<&str>| LinkRoutes::Post { id: 1, comment: comment.map(String::from) };
+        assert!(RouteMatch::Exact.is_active(&post(None), &post(None)));
+        assert!(!RouteMatch::Exact.is_active(&post(None), &post(Some("x"))));
+        assert!(RouteMatch::Variant.is_active(&post(None), &post(Some("x"))));
+        assert!(!RouteMatch::Variant.is_active(&post(None), &LinkRoutes::Home));
+
+        let index = LinkRoutes::Admin(LinkAdminRoutes::Index);
+        let users = LinkRoutes::Admin(LinkAdminRoutes::Users);
+        let settings = LinkRoutes::Admin(LinkAdminRoutes::Settings);
+        assert_eq!(RouteMatch::default(), RouteMatch::Variant);
+        assert!(!RouteMatch::Variant.is_active(&users, &settings));
+        assert!(!RouteMatch::Prefix.is_active(&users, &settings));
+        assert!(!RouteMatch::Prefix.is_active(&settings, &users));
+        assert!(RouteMatch::Prefix.is_active(&users, &users));
+        assert!(RouteMatch::Prefix.is_active(&post(None), &post(Some("x"))));
+        assert!(!RouteMatch::Prefix.is_active(&LinkRoutes::Home, &LinkRoutes::About));
+
+        // A link to the nested index covers the whole parent
+        assert!(RouteMatch::Prefix.is_active(&index, &users));
+        assert!(RouteMatch::Prefix.is_active(&index, &settings));
+        assert!(!RouteMatch::Variant.is_active(&index, &users));
+        assert!(!RouteMatch::Prefix.is_active(&index, &LinkRoutes::About));
+        // The top-level index doesn't cover every route
+        assert!(!RouteMatch::Prefix.is_active(&LinkRoutes::Home, &users));
+    }
+
+    #[test]
+    fn test_nested_route_links_resolve_against_the_parent_route() {
+        let (html, _) = render::<LinkRoutes>("/admin/users");
+        assert!(html.contains(r#"<a href="/admin/users" aria-current="page" class="active">"#), "{html}");
+        assert!(html.contains(r#"<a href="/admin/settings" class="">"#), "{html}");
+    }
+}
@@ -0,0 +1,109 @@
+//! `ROUTE_TABLE` introspection and `all_static_routes`.
+use crate::AdminRoutes;
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable, PartialEq, Debug)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum TableRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/about")]
+    About,
+
+    #[route(path = "/user/:id")]
+    User { id: u64 },
+
+    #[route(path = "/docs/:page")]
+    Docs {
+        page: String,
+        version: Option<u32>,
+        #[fragment]
+        section: Option<String>,
+    },
+
+    #[route(path = "/tags/*tags")]
+    Tags { tags: Vec<String> },
+
+    #[route(path = "/find")]
+    Find {
+        #[query(rename = "q")]
+        term: String,
+        #[query]
+        limit: u32,
+        lang: Option<String>,
+    },
+
+    #[parent_route(path = "/admin")]
+    Admin(AdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn HomeView() -> &'static str { "home" }
+fn AboutView() -> &'static str { "about" }
+fn UserView() -> &'static str { "user" }
+fn DocsView() -> &'static str { "docs" }
+fn TagsView() -> &'static str { "tags" }
+fn FindView() -> &'static str { "find" }
+fn AdminView() -> &'static str { "admin" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_routable::prelude::{ParamInfo, RouteInfo, RouteKind};
+
+    fn route_info(variant: &str) -> &'static RouteInfo {
+        TableRoutes::ROUTE_TABLE.iter().find(|info| info.variant == variant).unwrap()
+    }
+
+    #[test]
+    fn test_route_table_entries() {
+        let user = route_info("User");
+        assert_eq!(user.path, "/user/:id");
+        assert_eq!(user.kind, RouteKind::Route);
+        assert_eq!(user.params, &[ParamInfo { name: "id", ty: "u64" }]);
+        assert!(user.query.is_empty());
+        assert!(!user.fallback);
+
+        assert_eq!(route_info("Find").query, &["q", "limit", "lang"]);
+        assert_eq!(route_info("Docs").query, &["version"]);
+        assert_eq!(route_info("Tags").params, &[ParamInfo { name: "tags", ty: "Vec<String>" }]);
+        assert!(route_info("NotFound").fallback);
+
+        let admin = route_info("Admin");
+        assert_eq!(admin.kind, RouteKind::Parent);
+        assert_eq!(admin.nested, AdminRoutes::ROUTE_TABLE);
+    }
+
+    #[test]
+    fn test_route_entries_combine_nested_paths() {
+        let entries = TableRoutes::route_entries();
+        let users = entries.iter().find(|e| e.variant == "AdminUsers").unwrap();
+        assert_eq!(users.path, "/admin/users");
+        assert_eq!(users.info.path, "/users");
+        assert_eq!(users.kind, RouteKind::Route);
+        assert_eq!(users.variant_chain, vec!["Admin", "AdminUsers"]);
+        assert_eq!(entries.iter().find(|e| e.variant == "User").unwrap().path, "/user/:id");
+        assert_eq!(entries.len(), TableRoutes::ROUTE_TABLE.len() + AdminRoutes::ROUTE_TABLE.len());
+        // Built once and kept
+        assert!(std::ptr::eq(entries, TableRoutes::route_entries()));
+    }
+
+    #[test]
+    fn test_all_static_routes() {
+        let routes: Vec<_> = TableRoutes::all_static_routes().collect();
+        assert_eq!(
+            routes,
+            vec![
+                TableRoutes::Home,
+                TableRoutes::About,
+                TableRoutes::Admin(AdminRoutes::AdminUsers),
+                TableRoutes::Admin(AdminRoutes::AdminSettings),
+            ]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! `sitemap.xml` and `robots.txt` generation from `sitemap(...)` and `robots(...)` route attributes.
+use crate::AdminRoutes;
+use leptos_routable::prelude::{GuardOutcome, RouteGuard, Routable, Sitemap};
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum SitemapRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/about", sitemap(priority = 0.8, changefreq = "monthly"))]
+    About,
+
+    #[route(path = "/user/:id", sitemap(priority = 0.85, changefreq = "daily"))]
+    User { id: u64 },
+
+    #[route(path = "/search/:term")]
+    Search { term: String },
+
+    #[route(path = "/drafts", sitemap(exclude))]
+    Drafts,
+
+    #[route(path = "/account", guard = MembersOnly)]
+    Account,
+
+    #[route(path = "/pricing", guard = MembersOnly, sitemap(include))]
+    Pricing,
+
+    #[protected_route(path = "/billing", condition = "logged_out", redirect_path = "home", fallback = "NotFoundView")]
+    Billing,
+
+    #[parent_route(path = "/admin", sitemap(exclude), robots(disallow))]
+    Admin(AdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+impl Sitemap for SitemapRoutes {
+    fn sitemap_entries() -> Vec<Self> {
+        vec![
+            SitemapRoutes::User { id: 1 },
+            SitemapRoutes::User { id: 1 },
+            SitemapRoutes::Search { term: "a&b".to_string() },
+        ]
+    }
+}
+
+pub struct MembersOnly;
+
+impl RouteGuard for MembersOnly {
+    type Route = SitemapRoutes;
+
+    async fn check(_route: &SitemapRoutes) -> GuardOutcome<SitemapRoutes> {
+        GuardOutcome::Allow
+    }
+}
+
+fn logged_out() -> Option<bool> {
+    Some(false)
+}
+
+fn home() -> SitemapRoutes {
+    SitemapRoutes::Home
+}
+
+fn HomeView() -> &'static str { "home" }
+fn AboutView() -> &'static str { "about" }
+fn UserView() -> &'static str { "user" }
+fn SearchView() -> &'static str { "search" }
+fn DraftsView() -> &'static str { "drafts" }
+fn AccountView() -> &'static str { "account" }
+fn PricingView() -> &'static str { "pricing" }
+fn BillingView() -> &'static str { "billing" }
+fn AdminView() -> &'static str { "admin" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_routable::prelude::{ChangeFreq, SitemapOptions};
+
+    #[test]
+    fn test_sitemap_options_inherit_through_nesting() {
+        let about = SitemapOptions::for_route(&SitemapRoutes::About);
+        assert_eq!(about.priority, Some(0.8));
+        assert_eq!(about.changefreq, Some(ChangeFreq::Monthly));
+        assert!(!about.exclude);
+        assert!(SitemapOptions::for_route(&SitemapRoutes::Admin(AdminRoutes::AdminUsers)).exclude);
+        assert!(SitemapOptions::for_route(&SitemapRoutes::NotFound).exclude);
+    }
+
+    #[test]
+    fn test_guarded_routes_need_sitemap_include() {
+        assert!(SitemapOptions::for_route(&SitemapRoutes::Account).exclude);
+        assert!(SitemapOptions::for_route(&SitemapRoutes::Billing).exclude);
+        assert!(!SitemapOptions::for_route(&SitemapRoutes::Pricing).exclude);
+    }
+
+    #[test]
+    fn test_sitemap_xml() {
+        let xml = SitemapRoutes::sitemap_xml("https://example.com/");
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains("<loc>https://example.com/</loc>"));
+        assert!(xml.contains(
+            "<loc>https://example.com/about</loc>\n    <changefreq>monthly</changefreq>\n    <priority>0.8</priority>"
+        ));
+        assert!(xml.contains(
+            "<loc>https://example.com/user/1</loc>\n    <changefreq>daily</changefreq>\n    <priority>0.85</priority>"
+        ));
+        assert_eq!(xml.matches("/user/1<").count(), 1);
+        assert!(xml.contains("<loc>https://example.com/search/a%26b</loc>"));
+        assert!(!xml.contains("/admin"));
+        assert!(!xml.contains("/drafts"));
+        assert!(!xml.contains("/account"));
+        assert!(!xml.contains("/billing"));
+        assert!(xml.contains("<loc>https://example.com/pricing</loc>"));
+        assert!(!xml.contains("/404"));
+        assert!(xml.trim_end().ends_with("</urlset>"));
+    }
+
+    #[test]
+    fn test_robots_txt_only_disallows_robots_disallow() {
+        // `/drafts` and the guarded routes are only left out of the sitemap
+        assert_eq!(
+            SitemapRoutes::robots_txt("https://example.com/"),
+            "User-agent: *\nDisallow: /admin$\nDisallow: /admin/\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! Server-side rendering helpers shared by the guard, loader and lazy view tests.
+use futures::{executor::block_on, StreamExt};
+use leptos::prelude::*;
+use leptos_routable::prelude::Routable;
+
+/// Server-renders the routes of `R` at `url`, waiting for every `<Suspense>` to resolve.
+/// Returns the HTML and the path of a `<Redirect/>` rendered on the way, if any.
+pub(crate) fn render<R: Routable>(url: &str) -> (String, Option<String>) {
+    let _ = any_spawner::Executor::init_futures_executor();
+    let owner = Owner::new_root(Some(std::sync::Arc::new(hydration_context::SsrSharedContext::new())));
+    let redirect = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let html = owner.with(|| {
+        provide_context(leptos_router::location::RequestUrl::new(url));
+        let redirected = std::sync::Arc::clone(&redirect);
+        leptos_router::components::provide_server_redirect(move |path| {
+            *redirected.lock().unwrap() = Some(path.to_string());
+        });
+        let app = view! { <leptos_router::components::Router>{R::routes()}</leptos_router::components::Router> };
+        block_on(app.to_html_stream_in_order().collect::<String>())
+    });
+    let redirect = redirect.lock().unwrap().take();
+    (html, redirect)
+}
+
+/// Server-renders the routes of `R` at `url` out of order and returns the first chunk only,
+/// which holds the `<Suspense>` fallbacks of everything still pending.
+pub(crate) fn render_pending<R: Routable>(url: &str) -> String {
+    first_chunk(url, || view! { <leptos_router::components::Router>{R::routes()}</leptos_router::components::Router> })
+}
+
+/// The first chunk of `app` streamed out of order at `url`.
+pub(crate) fn first_chunk<V: IntoView>(url: &str, app: impl FnOnce() -> V) -> String {
+    let _ = any_spawner::Executor::init_futures_executor();
+    let owner = Owner::new_root(Some(std::sync::Arc::new(hydration_context::SsrSharedContext::new())));
+    owner.with(|| {
+        provide_context(leptos_router::location::RequestUrl::new(url));
+        let app = app();
+        block_on(app.to_html_stream_out_of_order().next()).unwrap_or_default()
+    })
+}
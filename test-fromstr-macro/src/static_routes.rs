@@ -0,0 +1,57 @@
+//! `static_params` functions and the concrete paths to prerender.
+use crate::AdminRoutes;
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable, PartialEq, Debug)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+pub enum StaticRoutes {
+    #[route(path = "/")]
+    Home,
+
+    #[route(path = "/about")]
+    About,
+
+    #[route(path = "/user/:id", static_params = "prerendered_users")]
+    User { id: u64 },
+
+    #[parent_route(path = "/admin")]
+    Admin(AdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn prerendered_users() -> Vec<StaticRoutes> {
+    vec![StaticRoutes::User { id: 1 }, StaticRoutes::User { id: 2 }, StaticRoutes::About]
+}
+
+fn HomeView() -> &'static str { "home" }
+fn AboutView() -> &'static str { "about" }
+fn UserView() -> &'static str { "user" }
+fn AdminView() -> &'static str { "admin" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_routable::prelude::static_params_map;
+
+    #[test]
+    fn test_static_paths() {
+        assert_eq!(
+            StaticRoutes::static_paths(),
+            vec!["/", "/about", "/user/1", "/user/2", "/admin/users", "/admin/settings"]
+        );
+    }
+
+    #[test]
+    fn test_static_params_map() {
+        let hrefs = ["/user/1", "/user/2?x=1", "/user/1", "/about", "/user/3/extra"].map(String::from);
+        let params = static_params_map("/user/:id", hrefs);
+        assert_eq!(params.get("id"), Some(&vec!["1".to_string(), "2".to_string()]));
+
+        let params = static_params_map("/files/*path", ["/files/a/b".to_string()]);
+        assert_eq!(params.get("path"), Some(&vec!["a/b".to_string()]));
+    }
+}
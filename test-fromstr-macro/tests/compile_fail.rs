@@ -0,0 +1,6 @@
+//! `compile_error!` diagnostics reported by `#[derive(Routable)]`.
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,17 @@
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+enum Routes {
+    #[route(path = "/user/:id", aliases = ["/u/:name"])]
+    User { id: u64 },
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn UserView() -> &'static str { "user" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+fn main() {}
@@ -0,0 +1,17 @@
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false, inject_title)]
+enum Routes {
+    #[route(path = "/", title = "Home")]
+    Home,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn HomeView() -> &'static str { "home" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+fn main() {}
@@ -0,0 +1,17 @@
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+enum Routes {
+    #[route(path = "/about", sitemap(exclude, include))]
+    About,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn AboutView() -> &'static str { "about" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+fn main() {}
@@ -0,0 +1,17 @@
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false, locales = ["en", "de"])]
+enum Routes {
+    #[route(path = "/settings", path_fr = "/parametres")]
+    Settings,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn SettingsView() -> &'static str { "settings" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+fn main() {}
@@ -0,0 +1,17 @@
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+enum Routes {
+    #[route(path = "/files/*path")]
+    Files { name: String },
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn FilesView() -> &'static str { "files" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+fn main() {}
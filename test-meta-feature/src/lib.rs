@@ -0,0 +1,33 @@
+//! Fixtures for the `meta` feature of `leptos-routable`, kept apart so the rest of the tests
+//! build without it.
+#![allow(non_snake_case)]
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable, PartialEq, Debug, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false, inject_title)]
+pub enum TitledRoutes {
+    #[route(path = "/", title = "Home")]
+    Home,
+
+    #[route(path = "/about", title = "About", meta("og:title" = "About us"))]
+    About,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn HomeView() -> &'static str { "home" }
+fn AboutView() -> &'static str { "about" }
+fn NotFoundView() -> &'static str { "notfound" }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject_title_builds_with_meta() {
+        assert_eq!(TitledRoutes::About.title(), Some("About".to_string()));
+        assert_eq!(TitledRoutes::About.meta(), &[("og:title", "About us")]);
+    }
+}
@@ -0,0 +1,6 @@
+//! `compile_error!` diagnostics of `#[derive(Routable)]` with the `meta` feature enabled.
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
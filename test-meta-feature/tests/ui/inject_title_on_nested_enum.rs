@@ -0,0 +1,30 @@
+use leptos_routable::prelude::Routable;
+
+#[derive(Routable, PartialEq, Clone)]
+#[routes(view_prefix = "", view_suffix = "View", transition = false)]
+enum Routes {
+    #[parent_route(path = "/admin")]
+    Admin(AdminRoutes),
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+#[derive(Routable, PartialEq, Clone)]
+#[routes(view_prefix = "Admin", view_suffix = "View", transition = false, inject_title)]
+enum AdminRoutes {
+    #[route(path = "/users")]
+    Users,
+
+    #[fallback]
+    #[route(path = "/404")]
+    NotFound,
+}
+
+fn AdminView() -> &'static str { "admin" }
+fn NotFoundView() -> &'static str { "notfound" }
+fn AdminUsersView() -> &'static str { "admin_users" }
+fn AdminNotFoundView() -> &'static str { "admin_notfound" }
+
+fn main() {}